@@ -0,0 +1,7 @@
+/**
+ * @doc analyzer Analyzer Module
+ * @description Lightweight source analysis for items following doc blocks
+ */
+
+export * from './item-detector';
+export * from './signature-parser';
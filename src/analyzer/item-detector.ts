@@ -0,0 +1,736 @@
+/**
+ * @doc item_detector Item Detector
+ * @description Detects the source item (function, struct, class...) following a doc block
+ * @description Uses line-based heuristics, not a full language parser
+ */
+
+import type { DocItem, ItemField, ItemKind, ItemMethod, ItemVariant } from '../types/index';
+import { parseGenerics, parseSignature, splitTopLevel } from './signature-parser';
+
+/**
+ * @doc SourceScope SourceScope
+ * @description Enclosing scope (impl, class, trait, module...) of a source line
+ * @description traitImpl marks a Rust trait implementation (impl Trait for Type), whose items are as visible as the trait
+ * @description visibility is the qualifier of a Rust inline module (mod name { ... }), as returned by readVisibility
+ */
+export interface SourceScope {
+  kind: ItemKind;
+  name: string;
+  traitImpl?: boolean;
+  visibility?: string;
+}
+
+interface ItemPattern {
+  kind: ItemKind;
+  regex: RegExp;
+}
+
+// Modifiers stripped before matching item patterns (visibility, qualifiers...)
+const MODIFIER_REGEX = /^(?:pub(?:\([^)]*\))?|export|default|declare|public|private|protected|abstract|async|unsafe|extern(?:\s+"[^"]*")?|local|inline|virtual|readonly|override|static(?=\s+(?!mut\b)\w+\s*[\w<*&(]))\s+/;
+
+// Rust `const`/`static` items are matched before modifiers are stripped
+const RUST_CONST_REGEX = /^(?:pub(?:\([^)]*\))?\s+)?(?:const|static)\s+(?:mut\s+)?([A-Z_][A-Z0-9_]*)\s*:/;
+
+const ITEM_PATTERNS: ItemPattern[] = [
+  { kind: 'impl', regex: /^impl(?:<.*?>)?\s+(?:[\w:]+(?:<.*?>)?\s+for\s+)?(?:[\w]+::)*(\w+)/ },
+  { kind: 'function', regex: /^(?:const\s+)?fn\s+(\w+)/ },
+  { kind: 'struct', regex: /^struct\s+(\w+)/ },
+  { kind: 'enum', regex: /^(?:const\s+)?enum\s+(?:class\s+)?(\w+)/ },
+  { kind: 'trait', regex: /^trait\s+(\w+)/ },
+  { kind: 'module', regex: /^(?:mod|namespace|module)\s+(\w+)/ },
+  { kind: 'type', regex: /^type\s+(\w+)/ },
+  { kind: 'class', regex: /^class\s+(\w+)/ },
+  { kind: 'interface', regex: /^interface\s+(\w+)/ },
+  { kind: 'function', regex: /^function\*?\s+([\w.:]+)/ },
+  { kind: 'function', regex: /^def\s+(\w+)/ },
+  { kind: 'const', regex: /^(?:const|let|var)\s+(\w+)/ },
+  { kind: 'function', regex: /^(?:[\w:<>,*&\s]+?\s+)?[*&]?(~?\w+)\s*\(/ },
+];
+
+// Item kinds that open a scope other items can belong to
+const SCOPE_KINDS: ItemKind[] = ['impl', 'struct', 'enum', 'trait', 'class', 'interface', 'module'];
+
+// Lines starting another comment are never items
+const COMMENT_LINE_REGEX = /^(?:\/\/|\/\*|\*|--|#(?!\[))/;
+
+// Rust attributes (#[default], #[serde(...)]) preceding an enum variant or a struct field
+const ATTRIBUTE_REGEX = /#!?\[[^\]]*\]/g;
+
+// Rust cfg attribute: #[cfg(condition)]
+const CFG_ATTRIBUTE_REGEX = /^#\[\s*cfg\s*\(([\s\S]*)\)\s*\]$/;
+
+// Maximum number of lines read for an enum or struct body
+const MAX_BODY_LINES = 500;
+
+// Rust visibility qualifier: pub, pub(crate), pub(super), pub(in path)...
+const VISIBILITY_REGEX = /^pub(?:\s*\(([^)]*)\))?(?=\s)/;
+
+// Visibilities documented by default (others need --document-private)
+const PUBLIC_VISIBILITIES = ['pub', 'pub(crate)'];
+
+// Rust trait implementation header (impl<T> Trait for Type)
+const TRAIT_IMPL_REGEX = /\bimpl\b[^{;]*\sfor\s/;
+
+// Implemented trait of a Rust trait impl header: last path segment, generic arguments dropped
+const IMPLEMENTED_TRAIT_REGEX = /\bimpl(?:<.*?>)?\s+(?:\w+::)*(\w+)(?:<.*?>)?\s+for\s/;
+
+// Method declaration of a Rust trait body (qualifiers before fn)
+const TRAIT_METHOD_REGEX = /^(?:(?:const|async|unsafe|extern(?:\s+"[^"]*")?)\s+)*fn\s+(\w+)/;
+
+// Named field of a Rust struct body: optional visibility, name and type
+const STRUCT_FIELD_REGEX = /^(pub(?:\s*\([^)]*\))?\s+)?(\w+)\s*:\s*([\s\S]+)$/;
+
+// Keywords that look like calls but never start an item
+const CONTROL_KEYWORDS = ['if', 'for', 'while', 'switch', 'match', 'return', 'catch', 'elif', 'else'];
+
+/**
+ * @doc detectItem detectItem
+ * @description Detects the item declared at or after a given line
+ * @description Skips blank lines and Rust attributes (#[inline], #[cfg(...)]...), then matches the first code line against known item patterns
+ * @param lines The source lines
+ * @param startIndex The 0-based index of the first line after the doc block
+ * @param extension The file extension (e.g., '.rs', '.ts')
+ * @returns The detected item or null if no recognizable item follows
+ */
+export function detectItem(
+  lines: string[],
+  startIndex: number,
+  extension: string
+): DocItem | null {
+  const { attributes, index, column } = readAttributes(lines, startIndex);
+
+  if (index >= lines.length) {
+    return null;
+  }
+
+  const line = lines[index].slice(column).trim();
+  if (COMMENT_LINE_REGEX.test(line)) {
+    return null;
+  }
+
+  const matched = matchItem(line);
+  if (!matched) {
+    return null;
+  }
+
+  const scopes = findEnclosingScopes(lines, index, extension);
+  const parent = [...scopes].reverse().find(scope => scope.kind !== 'module');
+
+  // Code following attributes on the same line (#[inline] fn f()) starts the signature
+  const source = column > 0 ? lines.map((text, i) => (i === index ? text.slice(column) : text)) : lines;
+
+  const item: DocItem = {
+    kind: matched.kind === 'function' && parent ? 'method' : matched.kind,
+    name: matched.name,
+    line: index + 1,
+    signature: readSignature(source, index, extension),
+  };
+
+  if (parent) {
+    item.parent = parent.name;
+  }
+
+  // Items of traits and trait impls have no qualifier of their own
+  if (extension.replace(/^\./, '') === 'rs' && item.kind !== 'impl' && parent?.kind !== 'trait' && !parent?.traitImpl) {
+    item.visibility = readVisibility(line);
+  }
+
+  // Inline modules (mod name { ... }) extend the module path of the file
+  const modules = scopes.filter(scope => scope.kind === 'module');
+  if (modules.length > 0) {
+    item.modules = modules.map(scope => scope.name);
+  }
+
+  // An item is no more visible than the inline modules enclosing it
+  const hidingModule = modules.find(scope => scope.visibility !== undefined && !isPublicVisibility(scope.visibility));
+  if (hidingModule && isPublicVisibility(item.visibility)) {
+    item.visibility = hidingModule.visibility;
+  }
+
+  const cfg = getCfgCondition(attributes);
+  if (cfg) {
+    item.cfg = cfg;
+  }
+
+  if (item.kind !== 'impl' && item.kind !== 'module' && item.kind !== 'const') {
+    const generics = parseGenerics(item.signature, item.name, extension);
+    if (generics) {
+      item.generics = generics;
+    }
+  }
+
+  if (item.kind === 'enum') {
+    item.variants = readEnumVariants(source, index);
+  }
+
+  if (item.kind === 'struct' && extension.replace(/^\./, '') === 'rs') {
+    item.fields = readStructFields(source, index);
+  }
+
+  if (item.kind === 'trait' && extension.replace(/^\./, '') === 'rs') {
+    item.methods = readTraitMethods(source, index);
+  }
+
+  const implemented = item.kind === 'impl' ? item.signature.match(IMPLEMENTED_TRAIT_REGEX) : null;
+  if (implemented) {
+    item.trait = implemented[1];
+  }
+
+  if (item.kind === 'function' || item.kind === 'method') {
+    const parsed = parseSignature(item.signature, item.name, extension);
+    if (parsed) {
+      item.params = parsed.params;
+      if (parsed.returnType !== undefined) {
+        item.returnType = parsed.returnType;
+      }
+      if (item.kind === 'method' && parsed.receiver) {
+        item.receiver = parsed.receiver;
+      }
+    }
+  }
+
+  return item;
+}
+
+/**
+ * @doc readAttributes readAttributes
+ * @description Reads the outer Rust attributes preceding an item, blank lines included
+ * @description Attributes may span several lines and stack on one line; brackets inside string literals are ignored
+ * @param lines The source lines
+ * @param startIndex The 0-based index of the first line to read
+ * @returns The attributes (whitespace collapsed) and the position of the code that follows them
+ */
+export function readAttributes(
+  lines: string[],
+  startIndex: number
+): { attributes: string[]; index: number; column: number } {
+  const attributes: string[] = [];
+  let index = startIndex;
+  let column = 0;
+
+  while (index < lines.length) {
+    const rest = lines[index].slice(column);
+    if (rest.trim() === '') {
+      index++;
+      column = 0;
+      continue;
+    }
+
+    const offset = rest.length - rest.trimStart().length;
+    if (!rest.startsWith('#[', offset)) {
+      break;
+    }
+
+    column += offset;
+    let text = '';
+    let depth = 0;
+    let inString = false;
+
+    while (index < lines.length) {
+      const char = lines[index][column];
+      if (char === undefined) {
+        text += ' ';
+        index++;
+        column = 0;
+        continue;
+      }
+
+      text += char;
+      column++;
+
+      if (inString) {
+        if (char === '\\') {
+          text += lines[index][column] ?? '';
+          column++;
+        } else if (char === '"') {
+          inString = false;
+        }
+      } else if (char === '"') {
+        inString = true;
+      } else if (char === '[') {
+        depth++;
+      } else if (char === ']' && --depth === 0) {
+        break;
+      }
+    }
+
+    attributes.push(text.replace(/\s+/g, ' ').trim());
+  }
+
+  return { attributes, index, column };
+}
+
+/**
+ * @doc readVisibility readVisibility
+ * @description Reads the visibility qualifier of a Rust item declaration
+ * @param line The trimmed declaration line
+ * @returns The qualifier with whitespace removed (pub, pub(crate), pub(super)...), or 'private' without one
+ */
+export function readVisibility(line: string): string {
+  const match = line.match(VISIBILITY_REGEX);
+  if (!match) {
+    return 'private';
+  }
+
+  return match[1] === undefined ? 'pub' : `pub(${match[1].replace(/\s+/g, ' ').trim()})`;
+}
+
+/**
+ * @doc isPublicVisibility isPublicVisibility
+ * @description Checks whether an item is documented by default: pub and pub(crate) items, and items without a parsed visibility (other languages, trait items)
+ * @param visibility The item visibility, if parsed
+ * @returns True if the item is documented without --document-private
+ */
+export function isPublicVisibility(visibility: string | undefined): boolean {
+  return visibility === undefined || PUBLIC_VISIBILITIES.includes(visibility);
+}
+
+/**
+ * @doc getCfgCondition getCfgCondition
+ * @description Gets the condition of the #[cfg(...)] attributes of an item
+ * @param attributes The item attributes
+ * @returns The condition (several cfg attributes are combined with all(...)), or null if the item has none
+ */
+export function getCfgCondition(attributes: string[]): string | null {
+  const conditions = attributes
+    .map(attribute => attribute.match(CFG_ATTRIBUTE_REGEX)?.[1].trim())
+    .filter((condition): condition is string => Boolean(condition));
+
+  if (conditions.length === 0) {
+    return null;
+  }
+
+  return conditions.length === 1 ? conditions[0] : `all(${conditions.join(', ')})`;
+}
+
+/**
+ * @doc matchItem matchItem
+ * @description Matches a single trimmed code line against item patterns
+ * @param line The trimmed code line
+ * @returns The item kind and name, or null if the line doesn't declare an item
+ */
+export function matchItem(line: string): { kind: ItemKind; name: string } | null {
+  const constMatch = line.match(RUST_CONST_REGEX);
+  if (constMatch) {
+    return { kind: 'const', name: constMatch[1] };
+  }
+
+  let stripped = line;
+  let previous = '';
+  while (stripped !== previous) {
+    previous = stripped;
+    stripped = stripped.replace(MODIFIER_REGEX, '');
+  }
+
+  for (const { kind, regex } of ITEM_PATTERNS) {
+    const match = stripped.match(regex);
+    if (match && !CONTROL_KEYWORDS.includes(match[1])) {
+      return { kind, name: match[1] };
+    }
+  }
+
+  return null;
+}
+
+/**
+ * @doc readSignature readSignature
+ * @description Reads the declaration of an item, possibly spanning several lines
+ * @description Stops at the body opening brace, a semicolon, or a Python colon
+ * @param lines The source lines
+ * @param index The 0-based index of the declaration line
+ * @param extension The file extension
+ * @returns The normalized signature (whitespace collapsed, body removed)
+ */
+export function readSignature(lines: string[], index: number, extension: string): string {
+  const isPython = extension.replace(/^\./, '') === 'py';
+  const parts: string[] = [];
+  let parenDepth = 0;
+
+  for (let i = index; i < lines.length && i < index + 20; i++) {
+    const line = lines[i].trim();
+    let end = -1;
+
+    for (let c = 0; c < line.length; c++) {
+      const char = line[c];
+      if (char === '(') {
+        parenDepth++;
+      } else if (char === ')') {
+        parenDepth--;
+      } else if (parenDepth === 0 && (char === '{' || char === ';')) {
+        end = c;
+        break;
+      }
+    }
+
+    if (end !== -1) {
+      parts.push(line.slice(0, end));
+      break;
+    }
+
+    parts.push(line);
+
+    if (parenDepth === 0 && (isPython || !continuesOnNextLine(line, lines[i + 1]))) {
+      break;
+    }
+  }
+
+  let signature = parts.join(' ').replace(/\s+/g, ' ').trim();
+  if (isPython) {
+    signature = signature.replace(/:$/, '');
+  }
+
+  return signature;
+}
+
+/**
+ * @doc readEnumVariants readEnumVariants
+ * @description Reads the variants of an enum body (Rust, TypeScript, C/C++)
+ * @description Comments, attributes and discriminants (= value) are ignored; tuple and struct variants keep their field types
+ * @param lines The source lines
+ * @param index The 0-based index of the enum declaration line
+ * @returns The variants in declaration order, empty for a forward declaration
+ */
+export function readEnumVariants(lines: string[], index: number): ItemVariant[] {
+  const body = readBody(lines, index);
+  return body === null ? [] : parseEnumBody(body);
+}
+
+/**
+ * @doc readStructFields readStructFields
+ * @description Reads the named fields of a Rust struct body, with their visibility
+ * @description Comments and attributes are ignored; tuple and unit structs have no named fields
+ * @param lines The source lines
+ * @param index The 0-based index of the struct declaration line
+ * @returns The fields in declaration order
+ */
+export function readStructFields(lines: string[], index: number): ItemField[] {
+  const body = readBody(lines, index);
+  if (body === null) {
+    return [];
+  }
+
+  const fields: ItemField[] = [];
+  for (const part of splitTopLevel(body, ',')) {
+    const match = part.trim().match(STRUCT_FIELD_REGEX);
+    if (match) {
+      fields.push({ name: match[2], type: match[3].replace(/\s+/g, ' ').trim(), public: Boolean(match[1]) });
+    }
+  }
+
+  return fields;
+}
+
+/**
+ * @doc readTraitMethods readTraitMethods
+ * @description Reads the methods declared in a Rust trait body, required ones and those with a default body
+ * @description Associated types and constants are ignored; comments and attributes are removed from the signatures
+ * @param lines The source lines
+ * @param index The 0-based index of the trait declaration line
+ * @returns The methods in declaration order
+ */
+export function readTraitMethods(lines: string[], index: number): ItemMethod[] {
+  const body = readBody(lines, index);
+  if (body === null) {
+    return [];
+  }
+
+  // Top-level declarations end with a semicolon (required method) or a body (default method)
+  const declarations: string[] = [];
+  let current = '';
+  let depth = 0;
+  let nesting = 0;
+
+  for (const char of body) {
+    if (depth > 0) {
+      if (char === '{') {
+        depth++;
+      } else if (char === '}') {
+        depth--;
+      }
+      continue;
+    }
+
+    if (char === '(' || char === '[') {
+      nesting++;
+    } else if (char === ')' || char === ']') {
+      nesting--;
+    } else if (nesting === 0 && (char === ';' || char === '{')) {
+      declarations.push(current);
+      current = '';
+      depth = char === '{' ? 1 : 0;
+      continue;
+    }
+    current += char;
+  }
+
+  const methods: ItemMethod[] = [];
+  for (const declaration of declarations) {
+    const signature = declaration.replace(/\s+/g, ' ').trim();
+    const match = signature.match(TRAIT_METHOD_REGEX);
+    if (match) {
+      methods.push({ name: match[1], signature });
+    }
+  }
+
+  return methods;
+}
+
+/**
+ * @doc readBody readBody
+ * @description Reads the text between the braces of an item body, without comments and attributes
+ * @param lines The source lines
+ * @param index The 0-based index of the declaration line
+ * @returns The body text, or null if the declaration ends (;) before a body opens
+ */
+function readBody(lines: string[], index: number): string | null {
+  let body = '';
+  let depth = 0;
+  let opened = false;
+  let inBlockComment = false;
+
+  for (let i = index; i < lines.length && i < index + MAX_BODY_LINES; i++) {
+    let line = lines[i];
+
+    if (inBlockComment) {
+      const end = line.indexOf('*/');
+      if (end === -1) {
+        continue;
+      }
+      line = line.slice(end + 2);
+      inBlockComment = false;
+    }
+
+    line = stripCode(line.replace(ATTRIBUTE_REGEX, '')).replace(/\/\*.*?\*\//g, '');
+    const blockStart = line.indexOf('/*');
+    if (blockStart !== -1) {
+      inBlockComment = true;
+      line = line.slice(0, blockStart);
+    }
+
+    for (const char of line) {
+      if (!opened) {
+        if (char === '{') {
+          opened = true;
+          depth = 1;
+        } else if (char === ';') {
+          return null;
+        }
+        continue;
+      }
+
+      if (char === '{') {
+        depth++;
+      } else if (char === '}') {
+        depth--;
+        if (depth === 0) {
+          return body;
+        }
+      }
+
+      body += char;
+    }
+
+    body += ' ';
+  }
+
+  return opened ? body : null;
+}
+
+/**
+ * @doc parseEnumBody parseEnumBody
+ * @description Parses the variants of an enum body (text between the braces)
+ * @param body The enum body, without comments
+ * @returns The variants in declaration order
+ */
+function parseEnumBody(body: string): ItemVariant[] {
+  const variants: ItemVariant[] = [];
+
+  for (const part of splitTopLevel(body, ',')) {
+    const match = part.trim().match(/^(\w+)\s*([({]?)/);
+    if (!match) {
+      continue;
+    }
+
+    const [, name, open] = match;
+    let fields: string[] = [];
+    if (open) {
+      const start = part.indexOf(open);
+      const end = part.lastIndexOf(open === '(' ? ')' : '}');
+      fields = splitTopLevel(part.slice(start + 1, end === -1 ? undefined : end), ',')
+        .map(field => field.replace(/\s+/g, ' ').trim())
+        .filter(field => field.length > 0);
+    }
+
+    variants.push({ name, fields });
+  }
+
+  return variants;
+}
+
+/**
+ * @doc continuesOnNextLine continuesOnNextLine
+ * @description Checks if a declaration continues on the next line (return type, where clause...)
+ * @param line The current trimmed line
+ * @param nextLine The next raw line, if any
+ * @returns True if the next line is part of the same declaration
+ */
+function continuesOnNextLine(line: string, nextLine?: string): boolean {
+  if (/(?:,|<|->|=|:)$/.test(line)) {
+    return true;
+  }
+
+  return nextLine !== undefined && /^\s*(?:->|where\b|extends\b|implements\b|:|\{)/.test(nextLine);
+}
+
+/**
+ * @doc findEnclosingScopes findEnclosingScopes
+ * @description Finds the scopes (impl, class, trait, module...) enclosing a source line
+ * @description Brace-based for most languages, indentation-based for Python
+ * @param lines The source lines
+ * @param index The 0-based index of the line
+ * @param extension The file extension
+ * @returns The enclosing scopes, outermost first
+ */
+export function findEnclosingScopes(
+  lines: string[],
+  index: number,
+  extension: string
+): SourceScope[] {
+  const ext = extension.replace(/^\./, '');
+
+  if (ext === 'py') {
+    return findIndentedScopes(lines, index);
+  }
+
+  if (ext === 'lua' || ext === 'sh' || ext === 'bash') {
+    return [];
+  }
+
+  const stack: Array<SourceScope & { depth: number }> = [];
+  let pending: SourceScope | null = null;
+  let depth = 0;
+  let inBlockComment = false;
+
+  for (let i = 0; i < index; i++) {
+    let line = lines[i];
+
+    if (inBlockComment) {
+      const end = line.indexOf('*/');
+      if (end === -1) {
+        continue;
+      }
+      line = line.slice(end + 2);
+      inBlockComment = false;
+    }
+
+    line = stripCode(line);
+    const blockStart = line.indexOf('/*');
+    if (blockStart !== -1) {
+      inBlockComment = !line.slice(blockStart).includes('*/');
+      line = line.slice(0, blockStart);
+    }
+
+    const trimmed = line.trim();
+    if (trimmed === '') {
+      continue;
+    }
+
+    const matched = matchItem(trimmed);
+    if (matched && SCOPE_KINDS.includes(matched.kind)) {
+      pending = { kind: matched.kind, name: matched.name };
+      if (matched.kind === 'impl' && TRAIT_IMPL_REGEX.test(trimmed)) {
+        pending.traitImpl = true;
+      } else if (matched.kind === 'module' && ext === 'rs') {
+        pending.visibility = readVisibility(trimmed);
+      }
+    }
+
+    for (const char of line) {
+      if (char === '{') {
+        depth++;
+        if (pending) {
+          stack.push({ ...pending, depth });
+          pending = null;
+        }
+      } else if (char === '}') {
+        depth--;
+        while (stack.length > 0 && stack[stack.length - 1].depth > depth) {
+          stack.pop();
+        }
+      } else if (char === ';') {
+        pending = null;
+      }
+    }
+  }
+
+  return stack.map(({ kind, name, traitImpl, visibility }) => {
+    const scope: SourceScope = { kind, name };
+    if (traitImpl) {
+      scope.traitImpl = traitImpl;
+    }
+    if (visibility) {
+      scope.visibility = visibility;
+    }
+    return scope;
+  });
+}
+
+/**
+ * @doc findIndentedScopes findIndentedScopes
+ * @description Finds enclosing class scopes using indentation (Python)
+ * @param lines The source lines
+ * @param index The 0-based index of the line
+ * @returns The enclosing scopes, outermost first
+ */
+function findIndentedScopes(lines: string[], index: number): SourceScope[] {
+  const scopes: SourceScope[] = [];
+  let indent = getIndentation(lines[index]);
+
+  for (let i = index - 1; i >= 0 && indent > 0; i--) {
+    const trimmed = lines[i].trim();
+    if (trimmed === '' || trimmed.startsWith('#')) {
+      continue;
+    }
+
+    const lineIndent = getIndentation(lines[i]);
+    if (lineIndent < indent) {
+      const match = trimmed.match(/^class\s+(\w+)/);
+      if (match) {
+        scopes.unshift({ kind: 'class', name: match[1] });
+      }
+      indent = lineIndent;
+    }
+  }
+
+  return scopes;
+}
+
+/**
+ * @doc getIndentation getIndentation
+ * @description Gets the indentation width of a line (tabs count as 4)
+ * @param line The source line
+ * @returns The indentation width
+ */
+function getIndentation(line: string): number {
+  const match = line.match(/^[ \t]*/);
+  return match ? match[0].replace(/\t/g, '    ').length : 0;
+}
+
+/**
+ * @doc stripCode stripCode
+ * @description Removes string literals and line comments from a code line
+ * @description Keeps brace counting from being fooled by braces in strings or comments
+ * @param line The source line
+ * @returns The line without strings and line comments
+ */
+function stripCode(line: string): string {
+  const withoutStrings = line
+    .replace(/"(?:[^"\\]|\\.)*"/g, '""')
+    .replace(/'(?:[^'\\]|\\.)'/g, "''")
+    .replace(/`(?:[^`\\]|\\.)*`/g, '``');
+
+  const commentStart = withoutStrings.indexOf('//');
+  const code = commentStart === -1 ? withoutStrings : withoutStrings.slice(0, commentStart);
+
+  return /^\s*(?:#|--|\*)/.test(code) ? '' : code;
+}
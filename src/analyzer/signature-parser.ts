@@ -0,0 +1,402 @@
+/**
+ * @doc signature_parser Signature Parser
+ * @description Extracts parameters and return types from function signatures
+ * @description Supports Rust, TypeScript/JavaScript, Python, C/C++ and Lua declarations
+ */
+
+import type { MethodReceiver, SignatureParam } from '../types/index';
+import { getLanguage } from '../parser/index';
+
+/**
+ * @doc ParsedSignature ParsedSignature
+ * @description Parameters and return type of a function signature
+ * @description returnType is null when the function explicitly returns nothing, undefined when unknown
+ * @description For Rust, receiver is the receiver of the first parameter, static when there is none
+ */
+export interface ParsedSignature {
+  params: SignatureParam[];
+  returnType?: string | null;
+  receiver?: MethodReceiver;
+}
+
+type SignatureLanguage = 'rust' | 'typescript' | 'javascript' | 'python' | 'c' | 'lua';
+
+// Signature syntax by frontend language (see LANGUAGE_FRONTENDS)
+const SIGNATURE_LANGUAGES: Record<string, SignatureLanguage> = {
+  rust: 'rust',
+  typescript: 'typescript',
+  javascript: 'javascript',
+  python: 'python',
+  c: 'c',
+  cpp: 'c',
+  hlsl: 'c',
+  lua: 'lua',
+};
+
+// Qualifiers ignored when reading a C/C++ return type
+const C_QUALIFIERS = /\b(?:static|inline|virtual|extern|explicit|constexpr|friend)\b/g;
+
+/**
+ * @doc parseSignature parseSignature
+ * @description Parses the parameter list and return type of a function signature
+ * @description Receivers (self, &self, this) are not counted as parameters
+ * @param signature The normalized signature (as read by readSignature)
+ * @param name The function name
+ * @param extension The file extension (e.g., '.rs')
+ * @returns The parsed signature or null if no parameter list is found
+ */
+export function parseSignature(
+  signature: string,
+  name: string,
+  extension: string
+): ParsedSignature | null {
+  const frontend = getLanguage(extension);
+  const language = frontend ? SIGNATURE_LANGUAGES[frontend] : undefined;
+  if (!language) {
+    return null;
+  }
+
+  const escapedName = name.replace(/[.*+?^${}()|[\]\\]/g, '\\$&');
+  const nameMatch = signature.match(new RegExp(`(?<![\\w$])${escapedName}\\s*[<(]`));
+  if (!nameMatch || nameMatch.index === undefined) {
+    return null;
+  }
+  const nameIndex = nameMatch.index;
+
+  const open = findParamListStart(signature, nameIndex + name.length);
+  if (open === -1) {
+    return null;
+  }
+
+  const close = findMatchingParen(signature, open);
+  if (close === -1) {
+    return null;
+  }
+
+  const rawParams = splitTopLevel(signature.slice(open + 1, close), ',')
+    .map(param => param.trim())
+    .filter(param => param.length > 0);
+  const params = rawParams
+    .map(param => parseParam(param, language))
+    .filter((param): param is SignatureParam => param !== null);
+
+  const parsed: ParsedSignature = {
+    params,
+    returnType: parseReturnType(signature, nameIndex, close, language),
+  };
+  if (language === 'rust') {
+    parsed.receiver = (rawParams.length > 0 ? parseReceiver(rawParams[0]) : null) ?? 'static';
+  }
+  return parsed;
+}
+
+/**
+ * @doc parseReceiver parseReceiver
+ * @description Reads the receiver of a Rust parameter: lifetimes are dropped (&'a mut self is &mut self), mut self is self
+ * @description Typed receivers (self: &Self, self: Box<Self>...) are read from their type
+ * @param param The trimmed parameter
+ * @returns The receiver, or null if the parameter is not self
+ */
+function parseReceiver(param: string): MethodReceiver | null {
+  const match = param.match(/^(&(?:'\w+\s*)?(mut\s+)?)?(?:mut\s+)?self\b\s*(?::\s*(.*))?$/);
+  if (!match) {
+    return null;
+  }
+
+  if (match[3] !== undefined) {
+    const typed = match[3].match(/^&(?:'\w+\s*)?(mut\s+)?Self$/);
+    return typed ? (typed[1] ? '&mut self' : '&self') : 'self';
+  }
+  if (match[1]) {
+    return match[2] ? '&mut self' : '&self';
+  }
+  return 'self';
+}
+
+/**
+ * @doc parseGenerics parseGenerics
+ * @description Extracts the type parameter names declared right after an item name (e.g. `Foo<'a, T: Clone, const N: usize>`)
+ * @description Lifetimes are kept with their quote, bounds and defaults are dropped
+ * @param signature The normalized signature (as read by readSignature)
+ * @param name The item name
+ * @param extension The file extension (e.g., '.rs')
+ * @returns The type parameter names, or null if the language has no generics syntax after names
+ */
+export function parseGenerics(signature: string, name: string, extension: string): string[] | null {
+  const frontend = getLanguage(extension);
+  const language = frontend ? SIGNATURE_LANGUAGES[frontend] : undefined;
+  if (language !== 'rust' && language !== 'typescript') {
+    return null;
+  }
+
+  const escapedName = name.replace(/[.*+?^${}()|[\]\\]/g, '\\$&');
+  const nameMatch = signature.match(new RegExp(`(?<![\\w$])${escapedName}\\s*<`));
+  if (!nameMatch || nameMatch.index === undefined) {
+    return [];
+  }
+
+  const open = nameMatch.index + nameMatch[0].length - 1;
+  let depth = 0;
+  let close = -1;
+  for (let i = open; i < signature.length && close === -1; i++) {
+    const char = signature[i];
+    if (char === '-' && signature[i + 1] === '>') {
+      i++;
+    } else if (char === '<') {
+      depth++;
+    } else if (char === '>') {
+      depth--;
+      if (depth === 0) {
+        close = i;
+      }
+    }
+  }
+
+  if (close === -1) {
+    return [];
+  }
+
+  return splitTopLevel(signature.slice(open + 1, close), ',')
+    .map(param => param.trim().replace(/^const\s+/, '').match(/^'?\w+/)?.[0])
+    .filter((param): param is string => param !== undefined);
+}
+
+/**
+ * @doc findParamListStart findParamListStart
+ * @description Finds the opening parenthesis of the parameter list, skipping generics
+ * @param signature The signature
+ * @param from The index right after the function name
+ * @returns The index of the opening parenthesis or -1
+ */
+function findParamListStart(signature: string, from: number): number {
+  let angleDepth = 0;
+
+  for (let i = from; i < signature.length; i++) {
+    const char = signature[i];
+    if (char === '-' && signature[i + 1] === '>') {
+      i++;
+    } else if (char === '<') {
+      angleDepth++;
+    } else if (char === '>') {
+      angleDepth--;
+    } else if (char === '(' && angleDepth === 0) {
+      return i;
+    }
+  }
+
+  return -1;
+}
+
+/**
+ * @doc findMatchingParen findMatchingParen
+ * @description Finds the parenthesis closing the one at a given index
+ * @param text The text to scan
+ * @param open The index of the opening parenthesis
+ * @returns The index of the closing parenthesis or -1
+ */
+function findMatchingParen(text: string, open: number): number {
+  let depth = 0;
+
+  for (let i = open; i < text.length; i++) {
+    if (text[i] === '(') {
+      depth++;
+    } else if (text[i] === ')') {
+      depth--;
+      if (depth === 0) {
+        return i;
+      }
+    }
+  }
+
+  return -1;
+}
+
+/**
+ * @doc splitTopLevel splitTopLevel
+ * @description Splits text on a separator, ignoring separators nested in brackets or generics
+ * @param text The text to split
+ * @param separator The single-character separator
+ * @returns The split parts
+ */
+export function splitTopLevel(text: string, separator: string): string[] {
+  const parts: string[] = [];
+  let depth = 0;
+  let current = '';
+
+  for (let i = 0; i < text.length; i++) {
+    const char = text[i];
+
+    if ((char === '-' || char === '=') && text[i + 1] === '>') {
+      current += char + '>';
+      i++;
+      continue;
+    }
+
+    if (char === '(' || char === '[' || char === '{' || char === '<') {
+      depth++;
+    } else if (char === ')' || char === ']' || char === '}' || char === '>') {
+      depth--;
+    } else if (char === separator && depth === 0) {
+      parts.push(current);
+      current = '';
+      continue;
+    }
+
+    current += char;
+  }
+
+  parts.push(current);
+  return parts;
+}
+
+/**
+ * @doc parseParam parseParam
+ * @description Parses a single parameter declaration
+ * @param param The trimmed parameter declaration
+ * @param language The signature language
+ * @returns The parameter name and type, or null for receivers (self, this)
+ */
+function parseParam(param: string, language: SignatureLanguage): SignatureParam | null {
+  switch (language) {
+    case 'rust': {
+      if (/^(?:&(?:'\w+\s+)?)?(?:mut\s+)?self\b/.test(param)) {
+        return null;
+      }
+      const colon = findTypeColon(param);
+      if (colon === -1) {
+        return { name: param, type: '' };
+      }
+      return {
+        name: param.slice(0, colon).replace(/^mut\s+/, '').trim(),
+        type: param.slice(colon + 1).trim(),
+      };
+    }
+
+    case 'typescript':
+    case 'javascript': {
+      const withoutDefault = splitTopLevel(param, '=')[0].trim();
+      const colon = findTypeColon(withoutDefault);
+      const rawName = colon === -1 ? withoutDefault : withoutDefault.slice(0, colon);
+      const name = rawName
+        .replace(/^(?:(?:public|private|protected|readonly|override)\s+)+/, '')
+        .replace(/^\.\.\./, '')
+        .replace(/\?$/, '')
+        .trim();
+      if (name === 'this') {
+        return null;
+      }
+      return { name, type: colon === -1 ? '' : withoutDefault.slice(colon + 1).trim() };
+    }
+
+    case 'python': {
+      const withoutDefault = splitTopLevel(param, '=')[0].trim();
+      const colon = withoutDefault.indexOf(':');
+      const name = (colon === -1 ? withoutDefault : withoutDefault.slice(0, colon)).replace(/^\*{1,2}/, '').trim();
+      if (name === 'self' || name === 'cls' || name === '' || name === '/') {
+        return null;
+      }
+      return { name, type: colon === -1 ? '' : withoutDefault.slice(colon + 1).trim() };
+    }
+
+    case 'c': {
+      const withoutDefault = splitTopLevel(param, '=')[0].trim();
+      if (withoutDefault === 'void') {
+        return null;
+      }
+      const match = withoutDefault.match(/^(.*?)(\w+)\s*((?:\[[^\]]*\])*)$/);
+      if (!match || !match[1].trim()) {
+        return { name: '', type: withoutDefault };
+      }
+      return { name: match[2], type: (match[1] + match[3]).trim() };
+    }
+
+    case 'lua':
+      return { name: param, type: '' };
+  }
+}
+
+/**
+ * @doc findTypeColon findTypeColon
+ * @description Finds the colon separating a parameter name from its type (ignores :: paths)
+ * @param param The parameter declaration
+ * @returns The index of the colon or -1
+ */
+function findTypeColon(param: string): number {
+  let depth = 0;
+
+  for (let i = 0; i < param.length; i++) {
+    const char = param[i];
+    if (char === '(' || char === '[' || char === '{' || char === '<') {
+      depth++;
+    } else if (char === ')' || char === ']' || char === '}' || char === '>') {
+      depth--;
+    } else if (char === ':' && depth === 0) {
+      if (param[i + 1] === ':') {
+        i++;
+        continue;
+      }
+      return i;
+    }
+  }
+
+  return -1;
+}
+
+/**
+ * @doc parseReturnType parseReturnType
+ * @description Reads the return type of a signature
+ * @param signature The signature
+ * @param nameIndex The index of the function name
+ * @param close The index of the parenthesis closing the parameter list
+ * @param language The signature language
+ * @returns The return type, null if nothing is returned, undefined if unknown
+ */
+function parseReturnType(
+  signature: string,
+  nameIndex: number,
+  close: number,
+  language: SignatureLanguage
+): string | null | undefined {
+  const after = signature.slice(close + 1).trim();
+
+  switch (language) {
+    case 'rust': {
+      const match = after.match(/^->\s*(.+?)(?:\s+where\b.*)?$/);
+      if (!match) {
+        return null;
+      }
+      const type = match[1].trim();
+      return type === '()' ? null : type;
+    }
+
+    case 'typescript':
+    case 'javascript': {
+      const match = after.match(/^:\s*(.+?)(?:\s*=>.*)?$/);
+      if (!match) {
+        return undefined;
+      }
+      const type = match[1].trim();
+      return type === 'void' || type === 'Promise<void>' ? null : type;
+    }
+
+    case 'python': {
+      const match = after.match(/^->\s*(.+)$/);
+      if (!match) {
+        return undefined;
+      }
+      const type = match[1].trim();
+      return type === 'None' ? null : type;
+    }
+
+    case 'c': {
+      const prefix = signature.slice(0, nameIndex).replace(/\w+::$/, '').replace(C_QUALIFIERS, '').trim();
+      if (!prefix) {
+        return undefined;
+      }
+      return prefix === 'void' ? null : prefix;
+    }
+
+    case 'lua':
+      return undefined;
+  }
+}
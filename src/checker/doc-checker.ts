@@ -0,0 +1,251 @@
+/**
+ * @doc doc_checker Doc Checker
+ * @description Validates doc blocks (param count, empty descriptions, returns) and finds undocumented public items
+ * @description Cross-references are checked once all files are parsed (see checkReferences)
+ */
+
+import { readFileSync } from 'node:fs';
+import path from 'node:path';
+import type { CanonicalDoc, CheckProblem, DocBlock, ScannerConfig } from '../types/index';
+import { StandardocError } from '../types/index';
+import { findWorkspaceFiles, scanFile } from '../scanner/index';
+import { generateCanonicalDoc, getTagData } from '../generator/index';
+import { isPublicVisibility } from '../analyzer/index';
+import { getDocumentPrivate, getTagPrefix } from '../extractor/index';
+import { checkReferences, findDeclaredTypes } from './reference-checker';
+
+/**
+ * @doc CheckOptions CheckOptions
+ * @description Options for the check command
+ */
+export interface CheckOptions {
+  strict?: boolean;
+  reportOrphans?: boolean;
+}
+
+// Public items that must be documented in strict mode, by extension
+const PUBLIC_ITEM_PATTERNS: Record<string, RegExp> = {
+  rs: /^\s*pub(?:\([^)]*\))?\s+(?:(?:const|async|unsafe|extern\s+"[^"]*")\s+)*(?:fn|struct|enum|trait)\s+(\w+)/,
+  ts: /^\s*export\s+(?:default\s+)?(?:declare\s+)?(?:async\s+)?(?:abstract\s+)?(?:function\*?|class|interface)\s+(\w+)/,
+  tsx: /^\s*export\s+(?:default\s+)?(?:declare\s+)?(?:async\s+)?(?:abstract\s+)?(?:function\*?|class|interface)\s+(\w+)/,
+  js: /^\s*export\s+(?:default\s+)?(?:async\s+)?(?:function\*?|class)\s+(\w+)/,
+  jsx: /^\s*export\s+(?:default\s+)?(?:async\s+)?(?:function\*?|class)\s+(\w+)/,
+};
+
+/**
+ * @doc checkWorkspace checkWorkspace
+ * @description Checks all doc blocks of a workspace
+ * @description Parsing errors (e.g. invalid @doc tag) are reported as problems instead of aborting
+ * @description Reference problems (dangling @see, {@link} and types, orphans with reportOrphans) follow the per-file problems, checked on the merged document
+ * @param config Scanner configuration with workspace root, include/exclude patterns
+ * @param options Check options (strict mode, orphan reporting)
+ * @returns All problems found, in file order
+ */
+export async function checkWorkspace(
+  config: ScannerConfig,
+  options: CheckOptions = {}
+): Promise<CheckProblem[]> {
+  const files = await findWorkspaceFiles(config);
+  const problems: CheckProblem[] = [];
+  const allBlocks: DocBlock[] = [];
+  const declaredTypes = new Set<string>();
+  const sources = new Map<string, string>();
+
+  for (const filePath of files) {
+    let content: string;
+    try {
+      content = readFileSync(filePath, 'utf-8');
+    } catch {
+      continue;
+    }
+
+    const relativePath = path.relative(config.workspaceRoot, filePath);
+    sources.set(relativePath, content);
+    for (const name of findDeclaredTypes(content)) {
+      declaredTypes.add(name);
+    }
+
+    let blocks: DocBlock[];
+    try {
+      blocks = scanFile(filePath, content, config.workspaceRoot);
+    } catch (error) {
+      if (error instanceof StandardocError) {
+        problems.push({
+          severity: 'error',
+          message: error.message,
+          file: relativePath,
+          line: error.line ?? 0,
+        });
+        continue;
+      }
+      throw error;
+    }
+
+    allBlocks.push(...blocks);
+    problems.push(...checkBlocks(blocks));
+
+    if (options.strict) {
+      problems.push(...findUndocumentedItems(content, filePath, relativePath, blocks));
+    }
+  }
+
+  let doc: CanonicalDoc;
+  try {
+    doc = generateCanonicalDoc(
+      getDocumentPrivate() ? allBlocks : allBlocks.filter(block => isPublicVisibility(block.item?.visibility))
+    );
+  } catch (error) {
+    if (error instanceof StandardocError) {
+      problems.push({ severity: 'error', message: error.message, file: error.file ?? '', line: error.line ?? 0 });
+      return problems;
+    }
+    throw error;
+  }
+
+  problems.push(...checkReferences(doc, { reportOrphans: options.reportOrphans, declaredTypes, sources }));
+
+  return problems;
+}
+
+/**
+ * @doc checkBlocks checkBlocks
+ * @description Checks a list of doc blocks against their detected source items
+ * @param blocks The doc blocks to check
+ * @returns The problems found
+ */
+export function checkBlocks(blocks: DocBlock[]): CheckProblem[] {
+  return blocks.flatMap(block => checkBlock(block));
+}
+
+/**
+ * @doc checkBlock checkBlock
+ * @description Checks a single doc block
+ * @description Reports empty descriptions, @param count mismatches, unknown @generic and @variant names, @default without @param or @field, @errors without Result and @returns on items returning nothing
+ * @param block The doc block to check
+ * @returns The problems found
+ */
+function checkBlock(block: DocBlock): CheckProblem[] {
+  const problems: CheckProblem[] = [];
+  const prefix = getTagPrefix();
+  const report = (message: string, severity: CheckProblem['severity'] = 'error') => {
+    problems.push({ severity, message, file: block.meta.path, line: block.meta.line });
+  };
+
+  const description = getTagData(block, 'description')
+    .map(fields => fields[0] || '')
+    .join('')
+    .trim();
+  if (!description) {
+    report(`empty description for '${block.label}'`);
+  }
+
+  if (getTagData(block, 'category').length > 1) {
+    report(`'${block.label}' has several ${prefix}category tags, only the first one is used`, 'warning');
+  }
+
+  if (getTagData(block, 'group').length > 1) {
+    report(`'${block.label}' has several ${prefix}group tags, only the first one is used`, 'warning');
+  }
+
+  for (const [target = '', value = ''] of getTagData(block, 'default')) {
+    if (!target) {
+      report(
+        `'${block.label}' declares ${prefix}default ${value} without a preceding ${prefix}param or ${prefix}field`,
+        'warning'
+      );
+    }
+  }
+
+  const item = block.item;
+  if (!item) {
+    return problems;
+  }
+
+  if (item.params) {
+    const documented = getTagData(block, 'param').length;
+    if (documented !== item.params.length) {
+      report(
+        `'${block.label}' documents ${documented} ${prefix}param but its signature has ${item.params.length} parameter(s)`
+      );
+    }
+  }
+
+  if (item.generics) {
+    for (const [name = ''] of getTagData(block, 'generic')) {
+      if (!item.generics.includes(name)) {
+        report(`'${block.label}' documents ${prefix}generic ${name} but ${item.name} has no such type parameter`, 'warning');
+      }
+    }
+  }
+
+  if (item.variants) {
+    for (const [name = ''] of getTagData(block, 'variant')) {
+      if (!item.variants.some(variant => variant.name === name)) {
+        report(`'${block.label}' documents ${prefix}variant ${name} but ${item.name} has no such variant`, 'warning');
+      }
+    }
+  }
+
+  // @errors only makes sense on Rust functions returning a Result
+  const returnType = item.returnType;
+  if (block.meta.ext === '.rs' && returnType !== undefined && getTagData(block, 'errors').length > 0) {
+    if (returnType === null || !/\bResult\b/.test(returnType)) {
+      report(`'${block.label}' declares ${prefix}errors but ${item.name} doesn't return a Result`, 'warning');
+    }
+  }
+
+  if (item.returnType === null && getTagData(block, 'returns').length > 0) {
+    report(`'${block.label}' declares ${prefix}returns but ${item.name} has no return type`);
+  }
+
+  return problems;
+}
+
+/**
+ * @doc findUndocumentedItems findUndocumentedItems
+ * @description Finds public items (pub fn, pub struct, export function...) without a doc block (strict mode)
+ * @param content The file content
+ * @param filePath The absolute file path
+ * @param relativePath The file path relative to the workspace root
+ * @param blocks The doc blocks found in the file
+ * @returns One problem per undocumented public item
+ */
+function findUndocumentedItems(
+  content: string,
+  filePath: string,
+  relativePath: string,
+  blocks: DocBlock[]
+): CheckProblem[] {
+  const pattern = PUBLIC_ITEM_PATTERNS[path.extname(filePath).toLowerCase().replace(/^\./, '')];
+  if (!pattern) {
+    return [];
+  }
+
+  const documentedLines = new Set(blocks.map(block => block.item?.line));
+  const problems: CheckProblem[] = [];
+  const lines = content.split(/\r?\n/);
+
+  for (let i = 0; i < lines.length; i++) {
+    const match = lines[i].match(pattern);
+    if (match && !documentedLines.has(i + 1)) {
+      problems.push({
+        severity: 'error',
+        message: `public item '${match[1]}' has no doc block`,
+        file: relativePath,
+        line: i + 1,
+      });
+    }
+  }
+
+  return problems;
+}
+
+/**
+ * @doc formatCheckProblem formatCheckProblem
+ * @description Formats a problem as "path:line: severity: message"
+ * @param problem The problem to format
+ * @returns The formatted problem
+ */
+export function formatCheckProblem(problem: CheckProblem): string {
+  return `${problem.file}:${problem.line}: ${problem.severity}: ${problem.message}`;
+}
@@ -0,0 +1,7 @@
+/**
+ * @doc checker Checker Module
+ * @description Validates doc annotations against the documented source items
+ */
+
+export * from './doc-checker';
+export * from './reference-checker';
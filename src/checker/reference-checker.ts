@@ -0,0 +1,176 @@
+/**
+ * @doc reference_checker Reference Checker
+ * @description Lint pass over the merged document: reports dangling @see, {@link} and type references, and optionally orphan entries
+ * @description Runs once all files are parsed, so forward references across files resolve
+ */
+
+import type { CanonicalDoc, CheckProblem, DocBlock } from '../types/index';
+import { buildDocEntry } from '../generator/index';
+import { getTagPrefix } from '../extractor/index';
+import { findInlineLinks, findParentBlock, findTypeId, resolveReferenceId } from '../resolver/index';
+
+/**
+ * @doc ReferenceCheckOptions ReferenceCheckOptions
+ * @description Options of the reference lint pass
+ * @description declaredTypes lists the type names declared in the sources (see findDeclaredTypes): a @param, @field or @returns type naming one of them without a doc entry is dangling
+ * @description sources maps file paths (as in block metadata) to their content, to locate each reference on its own line instead of the block line
+ */
+export interface ReferenceCheckOptions {
+  reportOrphans?: boolean;
+  declaredTypes?: Iterable<string>;
+  sources?: Map<string, string>;
+}
+
+// Type declarations in any supported language (struct Foo, enum Foo, class Foo, type Foo...)
+const TYPE_DECLARATION_REGEX = /\b(?:struct|enum|trait|union|class|interface|type)\s+([A-Z]\w*)/g;
+
+// Identifiers of a type (e.g. Option, Calculator in Option<Calculator>)
+const TYPE_IDENTIFIER_REGEX = /[A-Za-z_]\w*/g;
+
+/**
+ * @doc checkReferences checkReferences
+ * @description Checks the references between the entries of a merged document
+ * @description Unresolved @see and {@link} targets are errors; types declared in the sources but not documented are warnings, as are orphan entries with reportOrphans
+ * @description An orphan is an entry no other entry references, except members of a documented type and impl blocks of a documented type or trait (listed on its page)
+ * @param doc The canonical document containing all blocks
+ * @param options Declared types, sources and orphan reporting
+ * @returns The problems found, in document order
+ */
+export function checkReferences(doc: CanonicalDoc, options: ReferenceCheckOptions = {}): CheckProblem[] {
+  const problems: CheckProblem[] = [];
+  const prefix = getTagPrefix();
+  const declaredTypes = new Set(options.declaredTypes ?? []);
+  const referenced = new Set<string>();
+  const report = (block: DocBlock, needle: string, message: string, severity: CheckProblem['severity']) => {
+    problems.push({ severity, message, file: block.meta.path, line: findReferenceLine(block, needle, options.sources) });
+  };
+  const reference = (block: DocBlock, id: string) => {
+    if (doc[`doc.${id}`] !== block) {
+      referenced.add(id);
+    }
+  };
+
+  for (const [docKey, block] of Object.entries(doc)) {
+    const see = block.see;
+    for (const [target = ''] of Array.isArray(see) ? see : []) {
+      const id = target ? resolveReferenceId(doc, target) : null;
+      if (id) {
+        reference(block, id);
+      } else if (target) {
+        report(block, target, `unresolved ${prefix}see reference '${target}' in '${block.label}'`, 'error');
+      }
+    }
+
+    for (const [tag, data] of Object.entries(block)) {
+      // Examples are code: {@link} tokens in them are not links
+      if (tag === 'see' || tag === 'example' || !Array.isArray(data)) {
+        continue;
+      }
+
+      for (const field of data.flat()) {
+        for (const target of findInlineLinks(field)) {
+          const id = resolveReferenceId(doc, target);
+          if (id) {
+            reference(block, id);
+          } else {
+            const message = `unresolved {${prefix}link ${target}} in '${block.label}'`;
+            report(block, `{${prefix}link ${target}`, message, 'error');
+          }
+        }
+      }
+    }
+
+    const entry = buildDocEntry(docKey.slice('doc.'.length), block, findParentBlock(doc, block));
+    const types: Array<[string, string]> = [
+      ...entry.params.map(param => [`${prefix}param ${param.name}`, param.type] as [string, string]),
+      ...entry.fields.map(field => [`${prefix}field ${field.name}`, field.type] as [string, string]),
+      ...(entry.returns ? [[`${prefix}returns`, entry.returns.type] as [string, string]] : []),
+    ];
+    for (const [source, type] of types) {
+      for (const [name] of type.matchAll(TYPE_IDENTIFIER_REGEX)) {
+        const id = findTypeId(doc, name);
+        if (id) {
+          reference(block, id);
+        } else if (declaredTypes.has(name) && !isDocumentedName(doc, name)) {
+          report(block, name, `type '${name}' in ${source} of '${block.label}' has no doc entry`, 'warning');
+        }
+      }
+    }
+  }
+
+  if (options.reportOrphans) {
+    for (const [docKey, block] of Object.entries(doc)) {
+      if (!referenced.has(docKey.slice('doc.'.length)) && !isAttached(doc, block)) {
+        problems.push({
+          severity: 'warning',
+          message: `orphan entry '${block.label}': no other entry references it`,
+          file: block.meta.path,
+          line: block.meta.line,
+        });
+      }
+    }
+  }
+
+  return problems;
+}
+
+/**
+ * @doc findDeclaredTypes findDeclaredTypes
+ * @description Finds the names of the types declared in a source file (struct, enum, trait, class, interface, type alias...), documented or not
+ * @param content The file content
+ * @returns The declared type names
+ */
+export function findDeclaredTypes(content: string): string[] {
+  return [...content.matchAll(TYPE_DECLARATION_REGEX)].map(([, name]) => name);
+}
+
+/**
+ * @doc isDocumentedName isDocumentedName
+ * @description Tests whether an item with a given name has a doc entry, whatever its kind (e.g. a documented type alias)
+ * @param doc The canonical document containing all blocks
+ * @param name The item name
+ * @returns True if a block documents an item with this name
+ */
+function isDocumentedName(doc: CanonicalDoc, name: string): boolean {
+  return Object.values(doc).some(block => block.item?.name === name);
+}
+
+/**
+ * @doc isAttached isAttached
+ * @description Tests whether an entry is listed on the page of another one: a member of a documented type, or an impl block of a documented type or trait
+ * @param doc The canonical document containing all blocks
+ * @param block The entry block
+ * @returns True if the entry is reachable from a documented type or trait
+ */
+function isAttached(doc: CanonicalDoc, block: DocBlock): boolean {
+  const item = block.item;
+  if (item?.kind === 'impl') {
+    return findTypeId(doc, item.name) !== null || (!!item.trait && findTypeId(doc, item.trait) !== null);
+  }
+  return findParentBlock(doc, block) !== null;
+}
+
+/**
+ * @doc findReferenceLine findReferenceLine
+ * @description Finds the line of a reference: the first line of its doc block (down to the item line) containing it
+ * @param block The block holding the reference
+ * @param needle The reference text (e.g. "{@link calculator_add")
+ * @param sources File contents by path, if available
+ * @returns The line of the reference, or the block line if the source is unknown or doesn't contain it
+ */
+function findReferenceLine(block: DocBlock, needle: string, sources?: Map<string, string>): number {
+  const content = sources?.get(block.meta.path);
+  if (content === undefined) {
+    return block.meta.line;
+  }
+
+  const lines = content.split(/\r?\n/);
+  const last = Math.min(block.item?.line ?? lines.length, lines.length);
+  for (let line = block.meta.line; line <= last; line++) {
+    if (lines[line - 1].includes(needle)) {
+      return line;
+    }
+  }
+
+  return block.meta.line;
+}
@@ -3,29 +3,98 @@
 /**
  * @doc cli CLI Interface
  * @description Command-line interface for Standardoc
- * @description Commands: scan, transform, watch
+ * @description Commands: init, scan, transform, check, test, watch
  */
 
 import { parseArgs } from 'node:util';
 import path from 'node:path';
-import { scanWorkspace } from '../scanner/index';
+import { scanWorkspace, setParseCacheDir, validateGlobPatterns } from '../scanner/index';
+import { checkWorkspace, formatCheckProblem } from '../checker/index';
+import { extractRustExamples, formatExampleResult, testExamples } from '../tester/index';
+import { filterByMinVersion, parseVersion, type ParsedVersion } from '../resolver/index';
+import { watchWorkspace } from '../watcher/index';
 import { transformMarkdownFiles } from '../transformer/index';
-import { writeCanonicalDoc } from '../generator/index';
-import type { ScannerConfig, CanonicalDoc } from '../types/index';
-import { loadConfig, generateDefaultConfig, mergeCommentPatterns, type StandardocConfig } from '../config/config-loader';
-import { setCustomPatterns } from '../parser/comment-detector';
+import { getErrorCount, getWarningCount, setProgressMode } from '../diagnostics/index';
+import { diffDocEntries, formatDocDiff, readDocEntries } from '../differ/index';
+import {
+  writeCanonicalDoc,
+  writeDocEntries,
+  writeHtmlSingleFile,
+  writeHtmlSite,
+  writeMarkdownBook,
+  writeMarkdownSingleFile,
+  setCategoryOrder,
+  setMethodGroupOrder,
+  setFunctionOrder,
+  setOutputLanguage,
+  setShowInternal,
+  setSourceUrlTemplate,
+  setHtmlTemplate,
+  loadHtmlTemplate,
+  FUNCTION_ORDERS,
+  OUTPUT_LANGUAGES,
+  type FunctionOrder,
+  type WriteResult,
+} from '../generator/index';
+import type { ScannerConfig, CanonicalDoc, OutputFormat } from '../types/index';
+import {
+  loadConfig,
+  generateDefaultConfig,
+  mergeCommentPatterns,
+  OUTPUT_FORMATS,
+  type StandardocConfig,
+} from '../config/config-loader';
+import { setCustomPatterns, setDefaultLanguage } from '../parser/comment-detector';
 import { COMMENT_STYLES } from '../parser/comment-detector';
-import { setDocTagName } from '../extractor/tag-extractor';
+import { setCustomTags, setDocTagName, setDocumentPrivate, setTagPrefix } from '../extractor/tag-extractor';
 
 interface CLIOptions {
   command?: string;
+  path?: string;
+  newPath?: string;
   output?: string;
+  outputDir?: string;
+  clean?: boolean;
+  singleFile?: boolean;
+  format?: OutputFormat;
   include?: string[];
   exclude?: string[];
   watch?: boolean;
+  strict?: boolean;
+  reportOrphans?: boolean;
+  failOnWarnings?: boolean;
+  verbose?: boolean;
+  documentPrivate?: boolean;
+  showInternal?: boolean;
+  jobs?: number;
+  config?: string;
+  lang?: string;
+  minVersion?: ParsedVersion;
+  functionOrder?: FunctionOrder;
+  sourceUrl?: string;
+  tagPrefix?: string;
+  template?: string;
+  noCache?: boolean;
   help?: boolean;
 }
 
+// Commands of the CLI, in help order
+const COMMANDS = ['init', 'scan', 'transform', 'check', 'test', 'watch', 'diff'];
+
+// Default output (in .standardoc/) for each format
+const DEFAULT_OUTPUT_FILES: Record<OutputFormat, string> = {
+  canonical: 'doc.json',
+  json: 'ast.json',
+  html: 'html',
+  markdown: 'markdown',
+};
+
+// Default output file of html and markdown with --single-file
+const SINGLE_FILE_OUTPUTS: Partial<Record<OutputFormat, string>> = {
+  html: 'doc.html',
+  markdown: 'doc.md',
+};
+
 /**
  * @doc parseCLIArgs parseCLIArgs
  * @description Parses command-line arguments into CLIOptions
@@ -35,24 +104,263 @@ function parseCLIArgs(): CLIOptions {
   const { values, positionals } = parseArgs({
     options: {
       output: { type: 'string', short: 'o' },
+      'output-dir': { type: 'string' },
+      clean: { type: 'boolean' },
+      'single-file': { type: 'boolean' },
+      format: { type: 'string', short: 'f' },
       include: { type: 'string', multiple: true },
       exclude: { type: 'string', multiple: true },
       watch: { type: 'boolean', short: 'w' },
+      strict: { type: 'boolean' },
+      'report-orphans': { type: 'boolean' },
+      'fail-on-warnings': { type: 'boolean' },
+      verbose: { type: 'boolean' },
+      'document-private': { type: 'boolean' },
+      'show-internal': { type: 'boolean' },
+      jobs: { type: 'string', short: 'j' },
+      config: { type: 'string', short: 'c' },
+      lang: { type: 'string' },
+      'min-version': { type: 'string' },
+      'function-order': { type: 'string' },
+      'source-url': { type: 'string' },
+      'tag-prefix': { type: 'string' },
+      template: { type: 'string' },
+      'no-cache': { type: 'boolean' },
       help: { type: 'boolean', short: 'h' },
     },
     allowPositionals: true,
   });
 
+  // `standardoc --watch <path>` is a shorthand for `standardoc watch <path>`
+  if (values.watch && positionals[0] !== 'scan' && positionals[0] !== 'watch') {
+    if (positionals[0] !== undefined && COMMANDS.includes(positionals[0])) {
+      console.error(`--watch is only supported by scan (use standardoc watch, or drop --watch from ${positionals[0]})`);
+      process.exit(1);
+    }
+    positionals.unshift('watch');
+  }
+
   return {
     command: positionals[0],
+    path: positionals[1],
+    newPath: positionals[2],
     output: values.output,
+    outputDir: values['output-dir'],
+    clean: values.clean,
+    singleFile: values['single-file'],
+    format: parseFormat(values.format),
     include: values.include,
     exclude: values.exclude,
     watch: values.watch,
+    strict: values.strict,
+    reportOrphans: values['report-orphans'],
+    failOnWarnings: values['fail-on-warnings'],
+    verbose: values.verbose,
+    documentPrivate: values['document-private'],
+    showInternal: values['show-internal'],
+    jobs: parseJobs(values.jobs),
+    config: values.config,
+    lang: parseLang(values.lang),
+    minVersion: parseMinVersion(values['min-version']),
+    functionOrder: parseFunctionOrder(values['function-order']),
+    sourceUrl: values['source-url'],
+    tagPrefix: values['tag-prefix'],
+    template: values.template,
+    noCache: values['no-cache'],
     help: values.help,
   };
 }
 
+/**
+ * @doc parseFormat parseFormat
+ * @description Validates the --format option value
+ * @param value The raw option value
+ * @returns The output format, or undefined if not given (config or default applies)
+ */
+function parseFormat(value?: string): OutputFormat | undefined {
+  if (!value) {
+    return undefined;
+  }
+
+  if (OUTPUT_FORMATS.includes(value as OutputFormat)) {
+    return value as OutputFormat;
+  }
+
+  console.error(`Unknown format: ${value} (expected: ${OUTPUT_FORMATS.join(', ')})`);
+  process.exit(1);
+}
+
+/**
+ * @doc parseJobs parseJobs
+ * @description Validates the --jobs option value
+ * @param value The raw option value
+ * @returns The maximum number of parser threads, or undefined for the default (logical CPUs)
+ */
+function parseJobs(value?: string): number | undefined {
+  if (value === undefined) {
+    return undefined;
+  }
+
+  const jobs = Number(value);
+  if (Number.isInteger(jobs) && jobs > 0) {
+    return jobs;
+  }
+
+  console.error(`Invalid --jobs value: ${value} (expected a positive integer)`);
+  process.exit(1);
+}
+
+/**
+ * @doc parseLang parseLang
+ * @description Validates the --lang option value
+ * @param value The raw option value
+ * @returns The output language, or undefined for the default (English)
+ */
+function parseLang(value?: string): string | undefined {
+  if (value === undefined) {
+    return undefined;
+  }
+
+  if (OUTPUT_LANGUAGES.includes(value)) {
+    return value;
+  }
+
+  console.error(`Unknown language: ${value} (expected: ${OUTPUT_LANGUAGES.join(', ')})`);
+  process.exit(1);
+}
+
+/**
+ * @doc parseMinVersion parseMinVersion
+ * @description Validates the --min-version option value (read leniently, like @since)
+ * @param value The raw option value
+ * @returns The minimum version, or undefined to keep every entry
+ */
+function parseMinVersion(value?: string): ParsedVersion | undefined {
+  if (value === undefined) {
+    return undefined;
+  }
+
+  const version = parseVersion(value);
+  if (version) {
+    return version;
+  }
+
+  console.error(`Invalid --min-version value: ${value} (expected a version like 1.2.0)`);
+  process.exit(1);
+}
+
+/**
+ * @doc parseFunctionOrder parseFunctionOrder
+ * @description Validates the --function-order option value
+ * @param value The raw option value
+ * @returns The function order, or undefined for the default (source order)
+ */
+function parseFunctionOrder(value?: string): FunctionOrder | undefined {
+  if (value === undefined) {
+    return undefined;
+  }
+
+  if (FUNCTION_ORDERS.includes(value as FunctionOrder)) {
+    return value as FunctionOrder;
+  }
+
+  console.error(`Unknown function order: ${value} (expected: ${FUNCTION_ORDERS.join(', ')})`);
+  process.exit(1);
+}
+
+/**
+ * @doc applyConfigDefaults applyConfigDefaults
+ * @description Fills the options not given on the command line from the config file
+ * @description Precedence: CLI flags, then config values, then built-in defaults
+ * @param options Parsed CLI options
+ * @param config The loaded config, if any
+ * @param workspaceRoot The workspace root (config paths are relative to it)
+ * @returns The merged options
+ */
+function applyConfigDefaults(
+  options: CLIOptions,
+  config: StandardocConfig | null,
+  workspaceRoot: string
+): CLIOptions {
+  return {
+    ...options,
+    path: options.path ?? config?.input,
+    format: options.format ?? config?.format ?? 'canonical',
+    // --output-dir on the command line takes over the output of the config
+    output: options.output ?? (config?.output && !options.outputDir ? path.resolve(workspaceRoot, config.output) : undefined),
+    include: options.include ?? config?.include,
+    exclude: options.exclude ?? config?.exclude,
+    tagPrefix: options.tagPrefix ?? config?.tagPrefix,
+    template: options.template ?? (config?.template ? path.resolve(workspaceRoot, config.template) : undefined),
+  };
+}
+
+/**
+ * @doc resolveOutputPath resolveOutputPath
+ * @description Resolves the output file (canonical, json) or directory (html, markdown) of a run
+ * @description --output is used as is; --output-dir holds the default file name of file formats, or the files themselves for directory formats
+ * @description With --single-file, html and markdown are file formats (doc.html, doc.md)
+ * @param options Parsed CLI options (with config defaults)
+ * @param workspaceRoot The workspace root directory
+ * @returns The absolute output path
+ */
+function resolveOutputPath(options: CLIOptions, workspaceRoot: string): string {
+  if (options.output && options.outputDir) {
+    console.error('Error: --output and --output-dir cannot be used together');
+    process.exit(1);
+  }
+
+  if (options.output) {
+    return path.resolve(workspaceRoot, options.output);
+  }
+
+  const format = options.format || 'canonical';
+  const singleFile = options.singleFile ? SINGLE_FILE_OUTPUTS[format] : undefined;
+  if (options.singleFile && !singleFile) {
+    console.error('Error: --single-file only applies to html and markdown output');
+    process.exit(1);
+  }
+
+  const fileName = singleFile ?? DEFAULT_OUTPUT_FILES[format];
+  if (options.outputDir) {
+    const outputDir = path.resolve(workspaceRoot, options.outputDir);
+    return !singleFile && (format === 'html' || format === 'markdown')
+      ? outputDir
+      : path.join(outputDir, fileName);
+  }
+
+  return path.join(workspaceRoot, '.standardoc', fileName);
+}
+
+/**
+ * @doc printWriteResult printWriteResult
+ * @description Prints the outcome of writing an output directory
+ * @param label The output kind (e.g., 'HTML site')
+ * @param outputPath The output directory
+ * @param result The written and removed files
+ */
+function printWriteResult(label: string, outputPath: string, result: WriteResult): void {
+  const removed = result.removed.length > 0 ? `, removed ${result.removed.length} stale file(s)` : '';
+  console.log(`${label} written in: ${outputPath} (${result.written.length} file(s) updated${removed})`);
+}
+
+/**
+ * @doc finishRun finishRun
+ * @description Prints the diagnostic counts of a run, and exits with a non-zero status if --fail-on-warnings is set and any error or warning was reported (infos never fail a run)
+ * @param options Parsed CLI options (failOnWarnings)
+ * @param extraWarnings Warnings reported outside of the diagnostics (e.g. check problems)
+ * @param showErrors Whether the error count is printed even when it is 0 (check, after listing problems)
+ */
+function finishRun(options: CLIOptions, extraWarnings = 0, showErrors = false): void {
+  const errors = getErrorCount();
+  const warnings = getWarningCount() + extraWarnings;
+  console.log(`${errors > 0 || showErrors ? `${errors} error(s), ` : ''}${warnings} warning(s)`);
+  if (options.failOnWarnings && errors + warnings > 0) {
+    console.error('Failing because of diagnostics (--fail-on-warnings)');
+    process.exit(1);
+  }
+}
+
 /**
  * @doc printHelp printHelp
  * @description Prints CLI help message with usage and examples
@@ -66,47 +374,153 @@ Usage:
 
 Commands:
   init       Generate default .standardoc.json configuration file
-  scan       Scan workspace and generate canonical JSON
+  scan       Scan workspace (or a path) and generate output
   transform  Transform MD/MDX files with DSL
-  watch      Watch mode (scan + transform continuously)
+  check      Validate doc annotations without generating output
+  test       Check that the rust code blocks of @example tags compile (rustc)
+  watch      Regenerate output on file changes (optional path to watch)
+  diff       Report the API changes between two JSON outputs: diff <old.json> <new.json>
 
 Options:
-  -o, --output <path>    Output path for JSON (default: .standardoc/doc.json)
-  --include <pattern>    Glob patterns to include files (can be repeated)
-  --exclude <pattern>    Glob patterns to exclude files (can be repeated)
-  -w, --watch            Watch mode
+  -o, --output <path>    Output path (default: .standardoc/doc.json, .standardoc/<format> for html, markdown)
+  --output-dir <dir>     Output directory (html, markdown files, or the default file name of other formats)
+  --clean                Remove generated files of previous runs that are no longer produced (html, markdown)
+  --single-file          Emit html or markdown as one document with a table of contents (default: .standardoc/doc.html, doc.md)
+  -f, --format <format>  Output format: canonical, json, html, markdown (default: canonical)
+  --include <pattern>    Glob patterns to include files, relative to the scanned path (can be repeated)
+  --exclude <pattern>    Glob patterns to exclude files, applied after includes (can be repeated)
+  -w, --watch            Watch mode (same as the watch command)
+  --strict               check: also report public items without doc block
+  --report-orphans       check: also report entries no other entry references (@see, {@link}, types), except type members
+  --fail-on-warnings     Exit with a non-zero status if any warning was emitted (scan, check, test)
+  --verbose              Log each parsed file and a scan summary on stderr (instead of the file counter)
+  --document-private     Include private (non pub/pub(crate)) Rust items and fields, with a private marker
+  --show-internal        Render @internal entries, with an internal badge (html, markdown)
+  -j, --jobs <n>         Maximum number of parser threads (default: number of logical CPUs)
+  -c, --config <path>    Config file (default: .standardoc.json if it exists)
+  --lang <lang>          Language of the rendered labels: en, fr (default: en)
+  --min-version <v>      Only keep entries whose @since is at least v (entries without @since are kept)
+  --function-order <o>   Order of free functions: source, alpha (default: source)
+  --source-url <url>     Link each item to its definition, {path} and {line} are replaced (html, markdown)
+  --tag-prefix <token>   Token starting every tag, e.g. ! to read !doc and !param (default: @)
+  --template <path>      Render each html entry with a template file ({{ name }}, {% for param in params %}...)
+  --no-cache             Parse every file, ignoring the parse cache in .standardoc/cache
   -h, --help             Show this help
 
 Examples:
   standardoc init
   standardoc scan
   standardoc scan --output .standardoc --include "**/*.lua"
+  standardoc scan --format json --output docs.json
+  standardoc scan --format html --output site
+  standardoc scan --format markdown --output book/src/api
+  standardoc scan --format html --output-dir site --clean
+  standardoc scan --format markdown --single-file --output API.md
+  standardoc scan --format html --lang fr
+  standardoc scan --format markdown --min-version 2.0.0
+  standardoc scan --format html --template entry.html
+  standardoc scan --format html --source-url "https://github.com/me/repo/blob/main/{path}#L{line}"
   standardoc transform
-  standardoc watch
+  standardoc check src --strict
+  standardoc check src --report-orphans
+  standardoc check src --fail-on-warnings
+  standardoc scan --no-cache
+  standardoc test src
+  standardoc watch src --format html
+  standardoc diff v1/ast.json .standardoc/ast.json
+  standardoc --watch src
 `);
 }
 
+/**
+ * @doc runWatch runWatch
+ * @description Starts the watch mode and prints a summary after each generation
+ * @param config Scanner configuration of the directory to watch
+ * @param options Parsed CLI options (format)
+ * @param outputPath The output file or directory
+ */
+async function runWatch(config: ScannerConfig, options: CLIOptions, outputPath: string): Promise<void> {
+  console.log(`Watching ${config.workspaceRoot}...`);
+
+  await watchWorkspace(
+    config,
+    {
+      format: options.format || 'canonical',
+      outputPath,
+      minVersion: options.minVersion,
+      singleFile: options.singleFile,
+      onBuild: summary => {
+        console.log(
+          `Parsed ${summary.files} files, found ${summary.entries} entries, ` +
+          `updated ${summary.written} output file(s) in ${summary.elapsedMs}ms, ${summary.warnings} warning(s)`
+        );
+      },
+      onError: error => console.error('Error:', error),
+    }
+  );
+}
+
 /**
  * @doc main main
  * @description Main CLI entry point
  * @description Handles command routing and error handling
  */
 async function main() {
-  const options = parseCLIArgs();
+  const cliOptions = parseCLIArgs();
 
-  if (options.help || !options.command) {
+  if (cliOptions.help || !cliOptions.command) {
     printHelp();
     process.exit(0);
   }
 
   const workspaceRoot = process.cwd();
 
-  // Load custom config if it exists
-  const customConfig = loadConfig(workspaceRoot);
+  // Load custom config if it exists (--config must point to an existing file)
+  let customConfig: StandardocConfig | null;
+  try {
+    customConfig = loadConfig(workspaceRoot, cliOptions.config);
+  } catch (error) {
+    console.error(`Error: ${error instanceof Error ? error.message : String(error)}`);
+    process.exit(1);
+  }
+
+  const options = applyConfigDefaults(cliOptions, customConfig, workspaceRoot);
+
+  // Invalid globs fail at startup rather than silently matching nothing
+  try {
+    validateGlobPatterns([...(options.include ?? []), ...(options.exclude ?? [])]);
+  } catch (error) {
+    console.error(`Error: ${error instanceof Error ? error.message : String(error)}`);
+    process.exit(1);
+  }
+  setDefaultLanguage(customConfig?.language || null);
+  setOutputLanguage(options.lang || 'en');
+  setFunctionOrder(options.functionOrder || 'source');
+  setShowInternal(options.showInternal ?? false);
+  setProgressMode(options.verbose ? 'verbose' : 'counter');
+  setCategoryOrder(customConfig?.categories || []);
+  setMethodGroupOrder(customConfig?.methodGroups || []);
+  try {
+    setSourceUrlTemplate(options.sourceUrl ?? null);
+    setHtmlTemplate(options.template ? loadHtmlTemplate(path.resolve(workspaceRoot, options.template)) : null);
+  } catch (error) {
+    console.error(`Error: ${error instanceof Error ? error.message : String(error)}`);
+    process.exit(1);
+  }
 
   // Set custom doc tag name (default: "doc")
   const docTagName = customConfig?.docTag || 'doc';
   setDocTagName(docTagName);
+  try {
+    setTagPrefix(options.tagPrefix ?? '@');
+  } catch (error) {
+    console.error(`Error: ${error instanceof Error ? error.message : String(error)}`);
+    process.exit(1);
+  }
+  setCustomTags(customConfig?.customTags || []);
+  setDocumentPrivate(options.documentPrivate ?? false);
+  // Unchanged files are loaded from the cache of previous runs
+  setParseCacheDir(options.noCache ? null : path.join(workspaceRoot, '.standardoc', 'cache'));
 
   // Set custom comment patterns
   if (customConfig?.commentPatterns) {
@@ -114,13 +528,14 @@ async function main() {
     setCustomPatterns(mergedPatterns);
   }
 
-  const outputPath = options.output || path.join(workspaceRoot, '.standardoc', 'doc.json');
+  const outputPath = resolveOutputPath(options, workspaceRoot);
 
   const config: ScannerConfig = {
-    workspaceRoot,
+    workspaceRoot: options.path ? path.resolve(workspaceRoot, options.path) : workspaceRoot,
     includePatterns: options.include,
     excludePatterns: options.exclude,
     outputPath,
+    jobs: options.jobs,
   };
 
   try {
@@ -130,13 +545,40 @@ async function main() {
         break;
       }
       case 'scan': {
+        if (options.watch) {
+          await runWatch(config, options, outputPath);
+          break;
+        }
+
+        if (options.clean && (options.singleFile || (options.format !== 'html' && options.format !== 'markdown'))) {
+          console.warn('--clean only applies to html and markdown output (without --single-file)');
+        }
+        if (options.template && options.format !== 'html') {
+          console.warn('--template only applies to html output');
+        }
+
         console.log('Scanning workspace...');
-        const doc = await scanWorkspace(config);
+        const scanned = await scanWorkspace(config);
+        const doc = options.minVersion ? filterByMinVersion(scanned, options.minVersion) : scanned;
 
         console.log(`Found ${Object.keys(doc).length} documentable blocks`);
 
-        await writeCanonicalDoc(doc, outputPath);
-        console.log(`JSON canonical written in: ${outputPath}`);
+        if (options.format === 'json') {
+          await writeDocEntries(doc, outputPath);
+          console.log(`JSON entries written in: ${outputPath}`);
+        } else if (options.singleFile && options.format === 'html') {
+          printWriteResult('HTML document', outputPath, await writeHtmlSingleFile(doc, outputPath));
+        } else if (options.singleFile && options.format === 'markdown') {
+          printWriteResult('Markdown document', outputPath, await writeMarkdownSingleFile(doc, outputPath));
+        } else if (options.format === 'html') {
+          printWriteResult('HTML site', outputPath, await writeHtmlSite(doc, outputPath, { clean: options.clean }));
+        } else if (options.format === 'markdown') {
+          printWriteResult('Markdown files', outputPath, await writeMarkdownBook(doc, outputPath, { clean: options.clean }));
+        } else {
+          await writeCanonicalDoc(doc, outputPath);
+          console.log(`JSON canonical written in: ${outputPath}`);
+        }
+        finishRun(options);
         break;
       }
 
@@ -155,8 +597,63 @@ async function main() {
         break;
       }
 
+      case 'check': {
+        const problems = await checkWorkspace(config, { strict: options.strict, reportOrphans: options.reportOrphans });
+
+        for (const problem of problems) {
+          console.error(formatCheckProblem(problem));
+        }
+
+        const errorCount = problems.filter(problem => problem.severity === 'error').length;
+        const warningCount = problems.length - errorCount;
+        if (errorCount > 0) {
+          console.error(`${errorCount} problem(s) found, ${warningCount + getWarningCount()} warning(s)`);
+          process.exit(1);
+        }
+
+        if (problems.length === 0) {
+          console.log('No problems found');
+        }
+        finishRun(options, warningCount, problems.length > 0);
+        break;
+      }
+
+      case 'test': {
+        const doc = await scanWorkspace(config);
+        const results = testExamples(extractRustExamples(doc));
+
+        for (const result of results) {
+          console.log(formatExampleResult(result));
+          if (!result.passed && result.output) {
+            console.log(result.output.replace(/^/gm, '    '));
+          }
+        }
+
+        const failed = results.filter(result => !result.passed).length;
+        console.log(`${results.length - failed} passed, ${failed} failed`);
+        if (failed > 0) {
+          process.exit(1);
+        }
+        finishRun(options);
+        break;
+      }
+
       case 'watch': {
-        console.log('Watch mode (not implemented yet)');
+        await runWatch(config, options, outputPath);
+        break;
+      }
+
+      case 'diff': {
+        if (!options.path || !options.newPath) {
+          console.error('Usage: standardoc diff <old.json> <new.json>');
+          process.exit(1);
+        }
+
+        const diff = diffDocEntries(
+          readDocEntries(path.resolve(workspaceRoot, options.path)),
+          readDocEntries(path.resolve(workspaceRoot, options.newPath))
+        );
+        console.log(formatDocDiff(diff));
         break;
       }
 
@@ -6,6 +6,8 @@
 import { readFileSync, existsSync, writeFileSync } from 'node:fs';
 import path from 'node:path';
 import { COMMENT_STYLES } from '../parser/comment-detector';
+import type { OutputFormat } from '../types/index';
+import { StandardocError } from '../types/index';
 
 export interface CommentPatternConfig {
   single?: string[];
@@ -21,30 +23,177 @@ export interface TransformConfig {
 
 export interface StandardocConfig {
   docTag?: string; // Custom tag name (default: "doc")
+  tagPrefix?: string; // Token starting every tag (default: "@")
+  customTags?: string[]; // Additional tags accepted without unknown tag warning
   commentPatterns?: Record<string, CommentPatternConfig>;
   transform?: TransformConfig;
+  input?: string; // Directory to scan, relative to the workspace root (default: ".")
+  format?: OutputFormat; // Output format (default: "canonical")
+  output?: string; // Output file or directory, relative to the workspace root
+  include?: string[]; // Glob patterns of files to scan
+  exclude?: string[]; // Glob patterns of files to skip
+  language?: string; // Language of files whose extension has no known comment syntax
+  categories?: string[]; // Order of the @category groups (unlisted categories follow alphabetically)
+  methodGroups?: string[]; // Order of the @group method groups on type pages (unlisted groups follow alphabetically)
+  template?: string; // Template file of the html entries, relative to the workspace root
 }
 
+/**
+ * @doc OUTPUT_FORMATS OUTPUT_FORMATS
+ * @description Output formats accepted by --format and the config file
+ */
+export const OUTPUT_FORMATS: OutputFormat[] = ['canonical', 'json', 'html', 'markdown'];
+
+// Known keys of the config file and of its nested objects
+const CONFIG_KEYS = [
+  'docTag',
+  'tagPrefix',
+  'customTags',
+  'commentPatterns',
+  'transform',
+  'input',
+  'format',
+  'output',
+  'include',
+  'exclude',
+  'language',
+  'categories',
+  'methodGroups',
+  'template',
+];
+const TRANSFORM_KEYS = ['entry', 'output'];
+const COMMENT_PATTERN_KEYS = ['single', 'multi', 'docSingle', 'docMulti'];
+
+// Default config file name, looked up in the workspace root
+const CONFIG_FILE_NAME = '.standardoc.json';
+
 /**
  * @doc loadConfig loadConfig
- * @description Loads configuration from .standardoc.json if it exists
+ * @description Loads configuration from .standardoc.json (or an explicit config file) if it exists
  * @param workspaceRoot The workspace root directory
+ * @param explicitPath Optional config file path (--config), which must exist
  * @returns Configuration object or null if file doesn't exist
+ * @throws StandardocError if the explicit file is missing, can't be read or parsed, or the config has unknown keys or invalid values
  */
-export function loadConfig(workspaceRoot: string): StandardocConfig | null {
-  const configPath = path.join(workspaceRoot, '.standardoc.json');
+export function loadConfig(workspaceRoot: string, explicitPath?: string): StandardocConfig | null {
+  const configPath = explicitPath
+    ? path.resolve(workspaceRoot, explicitPath)
+    : path.join(workspaceRoot, CONFIG_FILE_NAME);
 
   if (!existsSync(configPath)) {
+    if (explicitPath) {
+      throw new StandardocError(`Config file not found: ${configPath}`, configPath);
+    }
     return null;
   }
 
+  let content: string;
   try {
-    const content = readFileSync(configPath, 'utf-8');
-    return JSON.parse(content) as StandardocConfig;
+    content = readFileSync(configPath, 'utf-8');
   } catch (error) {
-    console.warn(`Failed to load .standardoc.json: ${error}`);
-    return null;
+    throw new StandardocError(
+      `Cannot read ${path.basename(configPath)}: ${error instanceof Error ? error.message : String(error)}`,
+      configPath
+    );
+  }
+
+  let config: unknown;
+  try {
+    config = JSON.parse(content);
+  } catch (error) {
+    throw new StandardocError(
+      `Invalid JSON in ${path.basename(configPath)}: ${error instanceof Error ? error.message : String(error)}`,
+      configPath
+    );
+  }
+
+  validateConfig(config, configPath);
+  return config as StandardocConfig;
+}
+
+/**
+ * @doc validateConfig validateConfig
+ * @description Validates the keys and values of a parsed config file
+ * @param config The parsed JSON content
+ * @param configPath The config file path (for error messages)
+ * @throws StandardocError on the first unknown key or invalid value
+ */
+export function validateConfig(config: unknown, configPath: string): void {
+  const fileName = path.basename(configPath);
+
+  if (!isObject(config)) {
+    throw new StandardocError(`${fileName} must contain a JSON object`, configPath);
+  }
+
+  checkKeys(config, CONFIG_KEYS, '', configPath);
+
+  if (config.transform !== undefined) {
+    if (!isObject(config.transform)) {
+      throw new StandardocError(`Invalid value for 'transform' in ${fileName}: expected an object`, configPath);
+    }
+    checkKeys(config.transform, TRANSFORM_KEYS, 'transform.', configPath);
   }
+
+  if (config.commentPatterns !== undefined) {
+    if (!isObject(config.commentPatterns)) {
+      throw new StandardocError(`Invalid value for 'commentPatterns' in ${fileName}: expected an object`, configPath);
+    }
+    for (const [ext, patterns] of Object.entries(config.commentPatterns)) {
+      if (!isObject(patterns)) {
+        throw new StandardocError(`Invalid value for 'commentPatterns.${ext}' in ${fileName}: expected an object`, configPath);
+      }
+      checkKeys(patterns, COMMENT_PATTERN_KEYS, `commentPatterns.${ext}.`, configPath);
+    }
+  }
+
+  if (config.format !== undefined && !OUTPUT_FORMATS.includes(config.format as OutputFormat)) {
+    throw new StandardocError(
+      `Invalid value for 'format' in ${fileName}: ${JSON.stringify(config.format)} (expected: ${OUTPUT_FORMATS.join(', ')})`,
+      configPath
+    );
+  }
+
+  for (const key of ['docTag', 'tagPrefix', 'input', 'output', 'language', 'template']) {
+    if (config[key] !== undefined && typeof config[key] !== 'string') {
+      throw new StandardocError(`Invalid value for '${key}' in ${fileName}: expected a string`, configPath);
+    }
+  }
+
+  for (const key of ['customTags', 'include', 'exclude', 'categories', 'methodGroups']) {
+    const value = config[key];
+    if (value !== undefined && !(Array.isArray(value) && value.every(item => typeof item === 'string'))) {
+      throw new StandardocError(`Invalid value for '${key}' in ${fileName}: expected an array of strings`, configPath);
+    }
+  }
+}
+
+/**
+ * @doc checkKeys checkKeys
+ * @description Throws on the first key of an object that isn't in the known keys
+ * @param object The object to check
+ * @param knownKeys The accepted keys
+ * @param prefix The path of the object in the config (e.g., 'transform.')
+ * @param configPath The config file path (for error messages)
+ */
+function checkKeys(object: Record<string, unknown>, knownKeys: string[], prefix: string, configPath: string): void {
+  for (const key of Object.keys(object)) {
+    if (!knownKeys.includes(key)) {
+      throw new StandardocError(
+        `Unknown key '${prefix}${key}' in ${path.basename(configPath)} (expected one of: ${knownKeys.join(', ')})`,
+        configPath
+      );
+    }
+  }
+}
+
+/**
+ * @doc isObject isObject
+ * @description Checks whether a JSON value is a plain object
+ * @param value The value to check
+ * @returns True for objects that are not arrays or null
+ */
+function isObject(value: unknown): value is Record<string, unknown> {
+  return typeof value === 'object' && value !== null && !Array.isArray(value);
 }
 
 /**
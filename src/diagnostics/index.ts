@@ -0,0 +1,7 @@
+/**
+ * @doc diagnostics Diagnostics Module
+ * @description Diagnostics (errors, warnings, infos) and progress reporting of parsing and rendering
+ */
+
+export * from './reporter';
+export * from './progress';
@@ -0,0 +1,78 @@
+/**
+ * @doc progress Progress Reporter
+ * @description Reports the progress of a scan on stderr: a file counter, or one line per parsed file in verbose mode
+ * @description Never writes to stdout, so piped output stays clean
+ */
+
+/**
+ * @doc ProgressMode ProgressMode
+ * @description How scan progress is reported: not at all (library default), as an N/total counter, or one line per file (--verbose)
+ */
+export type ProgressMode = 'off' | 'counter' | 'verbose';
+
+// Current progress mode (default: silent, the CLI enables it)
+let PROGRESS_MODE: ProgressMode = 'off';
+
+// Whether the counter is drawn on the current stderr line (not yet ended by a newline)
+let counterDrawn = false;
+
+/**
+ * @doc setProgressMode setProgressMode
+ * @description Sets how scan progress is reported
+ * @param mode The progress mode
+ */
+export function setProgressMode(mode: ProgressMode): void {
+  PROGRESS_MODE = mode;
+}
+
+/**
+ * @doc getProgressMode getProgressMode
+ * @description Gets how scan progress is reported
+ * @returns The progress mode
+ */
+export function getProgressMode(): ProgressMode {
+  return PROGRESS_MODE;
+}
+
+/**
+ * @doc reportFileParsed reportFileParsed
+ * @description Reports a parsed file: "parsed file=<path> entries=<n>" in verbose mode, the updated N/total counter otherwise
+ * @description The counter is only drawn on a terminal and rewrites its own line; it ends with a newline once all files are parsed
+ * @description Diagnostics printed meanwhile erase it first (see clearProgressLine), so they don't land on its line
+ * @param file The file path, relative to the workspace root
+ * @param entries The number of doc blocks found in the file
+ * @param done The number of files parsed so far, this one included
+ * @param total The number of files to parse
+ */
+export function reportFileParsed(file: string, entries: number, done: number, total: number): void {
+  if (PROGRESS_MODE === 'verbose') {
+    console.error(`parsed file=${file} entries=${entries}`);
+  } else if (PROGRESS_MODE === 'counter' && process.stderr.isTTY) {
+    process.stderr.write(`\rParsing files ${done}/${total}${done === total ? '\n' : ''}`);
+    counterDrawn = done !== total;
+  }
+}
+
+/**
+ * @doc clearProgressLine clearProgressLine
+ * @description Erases the counter from its line, so a message printed next starts on a clean line; the next file parsed redraws it
+ */
+export function clearProgressLine(): void {
+  if (counterDrawn) {
+    process.stderr.write('\r\x1b[K');
+    counterDrawn = false;
+  }
+}
+
+/**
+ * @doc reportScanSummary reportScanSummary
+ * @description Reports the summary of a scan in verbose mode: "scanned files=<n> entries=<n> elapsed=<ms>ms"
+ * @param files The number of parsed files
+ * @param entries The number of documented entries
+ * @param elapsedMs The duration of the scan, in milliseconds
+ */
+export function reportScanSummary(files: number, entries: number, elapsedMs: number): void {
+  if (PROGRESS_MODE === 'verbose') {
+    console.error(`scanned files=${files} entries=${entries} elapsed=${elapsedMs}ms`);
+  }
+}
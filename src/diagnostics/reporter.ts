@@ -0,0 +1,206 @@
+/**
+ * @doc reporter Diagnostics Reporter
+ * @description Collects the diagnostics (errors, warnings, infos) of a run, prints them on stderr and counts them for the end-of-run summary
+ * @description Library users can read them as data with getDiagnostics or captureDiagnostics
+ */
+
+import { clearProgressLine } from './progress';
+
+/**
+ * @doc DiagnosticSeverity DiagnosticSeverity
+ * @description Severity of a diagnostic: errors skip the faulty file, warnings and infos never stop a run
+ */
+export type DiagnosticSeverity = 'error' | 'warning' | 'info';
+
+/**
+ * @doc Diagnostic Diagnostic
+ * @description Problem found while parsing or rendering, with its location (1-based line and column) when known
+ */
+export interface Diagnostic {
+  severity: DiagnosticSeverity;
+  message: string;
+  file?: string;
+  line?: number;
+  col?: number;
+}
+
+let diagnostics: Diagnostic[] = [];
+
+// Whether reported diagnostics are printed on stderr (disabled while capturing)
+let printDiagnostics = true;
+
+/**
+ * @doc reportDiagnostic reportDiagnostic
+ * @description Records a diagnostic and prints it on stderr (never stdout, so piped output stays clean), erasing the progress counter first
+ * @param diagnostic The diagnostic
+ */
+export function reportDiagnostic(diagnostic: Diagnostic): void {
+  diagnostics.push(diagnostic);
+
+  if (printDiagnostics) {
+    clearProgressLine();
+    console.warn(formatDiagnostic(diagnostic));
+  }
+}
+
+/**
+ * @doc reportError reportError
+ * @description Reports an error diagnostic (e.g. a file that can't be parsed)
+ * @param message The error message
+ * @param file Optional file path the error refers to
+ * @param line Optional line number the error refers to
+ * @param col Optional column number the error refers to
+ */
+export function reportError(message: string, file?: string, line?: number, col?: number): void {
+  reportDiagnostic(createDiagnostic('error', message, file, line, col));
+}
+
+/**
+ * @doc reportWarning reportWarning
+ * @description Reports a warning diagnostic
+ * @description Format: warning: <message> at <file>:<line>
+ * @param message The warning message
+ * @param file Optional file path the warning refers to
+ * @param line Optional line number the warning refers to
+ * @param col Optional column number the warning refers to
+ */
+export function reportWarning(message: string, file?: string, line?: number, col?: number): void {
+  reportDiagnostic(createDiagnostic('warning', message, file, line, col));
+}
+
+/**
+ * @doc reportInfo reportInfo
+ * @description Reports an informational diagnostic (never fails a run, even with --fail-on-warnings)
+ * @param message The message
+ * @param file Optional file path the message refers to
+ * @param line Optional line number the message refers to
+ * @param col Optional column number the message refers to
+ */
+export function reportInfo(message: string, file?: string, line?: number, col?: number): void {
+  reportDiagnostic(createDiagnostic('info', message, file, line, col));
+}
+
+/**
+ * @doc addDiagnostics addDiagnostics
+ * @description Reports diagnostics collected elsewhere (e.g. by parser threads), in order
+ * @param list The diagnostics
+ */
+export function addDiagnostics(list: Diagnostic[]): void {
+  for (const diagnostic of list) {
+    reportDiagnostic(diagnostic);
+  }
+}
+
+/**
+ * @doc formatDiagnostic formatDiagnostic
+ * @description Formats a diagnostic as "<severity>: <message> at <file>:<line>:<col>" (location parts omitted when unknown)
+ * @param diagnostic The diagnostic
+ * @returns The formatted diagnostic
+ */
+export function formatDiagnostic(diagnostic: Diagnostic): string {
+  const { severity, message, file, line, col } = diagnostic;
+
+  let location = '';
+  if (file) {
+    location = ` at ${file}`;
+    if (line !== undefined) {
+      location += `:${line}${col !== undefined ? `:${col}` : ''}`;
+    }
+  }
+
+  return `${severity}: ${message}${location}`;
+}
+
+/**
+ * @doc captureDiagnostics captureDiagnostics
+ * @description Runs a function collecting its diagnostics as data instead of printing them (editor integrations)
+ * @description The diagnostics of the run are not added to the global list
+ * @param run The function to run
+ * @returns The function result and its diagnostics, in report order
+ */
+export function captureDiagnostics<T>(run: () => T): { result: T; diagnostics: Diagnostic[] } {
+  const previous = { diagnostics, printDiagnostics };
+  diagnostics = [];
+  printDiagnostics = false;
+
+  try {
+    return { result: run(), diagnostics };
+  } finally {
+    diagnostics = previous.diagnostics;
+    printDiagnostics = previous.printDiagnostics;
+  }
+}
+
+/**
+ * @doc setDiagnosticsPrinting setDiagnosticsPrinting
+ * @description Enables or disables printing reported diagnostics on stderr (they are recorded either way)
+ * @param enabled Whether diagnostics are printed (default: true)
+ */
+export function setDiagnosticsPrinting(enabled: boolean): void {
+  printDiagnostics = enabled;
+}
+
+/**
+ * @doc getDiagnostics getDiagnostics
+ * @description Gets the diagnostics reported since the last reset
+ * @returns The diagnostics, in report order
+ */
+export function getDiagnostics(): Diagnostic[] {
+  return [...diagnostics];
+}
+
+/**
+ * @doc getErrorCount getErrorCount
+ * @description Gets the number of errors reported since the last reset
+ * @returns The error count
+ */
+export function getErrorCount(): number {
+  return diagnostics.filter(diagnostic => diagnostic.severity === 'error').length;
+}
+
+/**
+ * @doc getWarningCount getWarningCount
+ * @description Gets the number of warnings reported since the last reset
+ * @returns The warning count
+ */
+export function getWarningCount(): number {
+  return diagnostics.filter(diagnostic => diagnostic.severity === 'warning').length;
+}
+
+/**
+ * @doc resetDiagnostics resetDiagnostics
+ * @description Clears the reported diagnostics (e.g. between watch mode runs)
+ */
+export function resetDiagnostics(): void {
+  diagnostics = [];
+}
+
+/**
+ * @doc createDiagnostic createDiagnostic
+ * @description Creates a diagnostic, leaving out the unknown location parts
+ * @param severity The severity
+ * @param message The message
+ * @param file Optional file path
+ * @param line Optional line number
+ * @param col Optional column number
+ * @returns The diagnostic
+ */
+function createDiagnostic(
+  severity: DiagnosticSeverity,
+  message: string,
+  file?: string,
+  line?: number,
+  col?: number
+): Diagnostic {
+  const diagnostic: Diagnostic = { severity, message };
+  if (file) {
+    diagnostic.file = file;
+  }
+  if (line !== undefined) {
+    diagnostic.line = line;
+  }
+  if (col !== undefined) {
+    diagnostic.col = col;
+  }
+  return diagnostic;
+}
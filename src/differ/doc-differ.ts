@@ -0,0 +1,261 @@
+/**
+ * @doc doc_differ Doc Differ
+ * @description Compares two JSON entry files (scan --format json) and reports the API changes between them
+ * @description Entries are matched by key; removals of public entries are flagged as potential breaking changes
+ */
+
+import { readFileSync } from 'node:fs';
+import type { DocEntry, DocParam } from '../types/index';
+import { StandardocError } from '../types/index';
+
+/**
+ * @doc DocChangeKind DocChangeKind
+ * @description Kind of change of an entry between two doc sets
+ */
+export type DocChangeKind = 'added' | 'removed' | 'modified';
+
+/**
+ * @doc DocEditKind DocEditKind
+ * @description Kind of edit of a modified entry: its signature, a parameter, field or return type, or a description
+ */
+export type DocEditKind = 'signature' | 'type' | 'description';
+
+/**
+ * @doc DocEdit DocEdit
+ * @description Edit of a modified entry; target names what changed (signature, description, param x, field x, returns)
+ * @description before and after are empty for a parameter or field that was added or removed
+ */
+export interface DocEdit {
+  kind: DocEditKind;
+  target: string;
+  before: string;
+  after: string;
+}
+
+/**
+ * @doc DocChange DocChange
+ * @description Change of an entry; breaking is true for the removal of a public entry, edits lists the edits of a modified entry
+ */
+export interface DocChange {
+  kind: DocChangeKind;
+  id: string;
+  name: string;
+  entryKind: string;
+  breaking: boolean;
+  edits: DocEdit[];
+}
+
+/**
+ * @doc DocDiff DocDiff
+ * @description Changes between two doc sets, grouped by kind, each group in key order
+ */
+export interface DocDiff {
+  added: DocChange[];
+  removed: DocChange[];
+  modified: DocChange[];
+}
+
+/**
+ * @doc readDocEntries readDocEntries
+ * @description Reads a JSON entries file written by scan --format json
+ * @param filePath The file path
+ * @returns The entries of the file
+ * @throws StandardocError if the file can't be read or is not a list of entries
+ */
+export function readDocEntries(filePath: string): DocEntry[] {
+  let parsed: unknown;
+  try {
+    parsed = JSON.parse(readFileSync(filePath, 'utf-8'));
+  } catch (error) {
+    throw new StandardocError(
+      `Cannot read ${filePath}: ${error instanceof Error ? error.message : String(error)}`,
+      filePath
+    );
+  }
+
+  if (!Array.isArray(parsed) || parsed.some(entry => typeof entry?.id !== 'string')) {
+    throw new StandardocError(
+      `${filePath} is not a JSON entries file (generate one with standardoc scan --format json)`,
+      filePath
+    );
+  }
+
+  return parsed as DocEntry[];
+}
+
+/**
+ * @doc diffDocEntries diffDocEntries
+ * @description Compares two doc sets: entries only in the new set are added, entries only in the old set are removed, the others are modified if their signature, types or descriptions differ
+ * @param oldEntries The entries of the old version
+ * @param newEntries The entries of the new version
+ * @returns The changes, grouped by kind
+ */
+export function diffDocEntries(oldEntries: DocEntry[], newEntries: DocEntry[]): DocDiff {
+  const oldById = new Map(oldEntries.map(entry => [entry.id, entry]));
+  const newById = new Map(newEntries.map(entry => [entry.id, entry]));
+  const ids = [...new Set([...oldById.keys(), ...newById.keys()])].sort(compareIds);
+  const diff: DocDiff = { added: [], removed: [], modified: [] };
+
+  for (const id of ids) {
+    const before = oldById.get(id);
+    const after = newById.get(id);
+
+    if (!before && after) {
+      diff.added.push(createChange('added', after, []));
+    } else if (before && !after) {
+      diff.removed.push(createChange('removed', before, []));
+    } else if (before && after) {
+      const edits = diffEntry(before, after);
+      if (edits.length > 0) {
+        diff.modified.push(createChange('modified', after, edits));
+      }
+    }
+  }
+
+  return diff;
+}
+
+/**
+ * @doc diffEntry diffEntry
+ * @description Lists the edits between two versions of an entry, in a fixed order: signature, description, fields, parameters, return value
+ * @param before The old entry
+ * @param after The new entry
+ * @returns The edits, empty if the entry is unchanged
+ */
+function diffEntry(before: DocEntry, after: DocEntry): DocEdit[] {
+  const edits: DocEdit[] = [];
+  const push = (kind: DocEditKind, target: string, old: string, current: string) => {
+    if (old !== current) {
+      edits.push({ kind, target, before: old, after: current });
+    }
+  };
+
+  push('signature', 'signature', before.signature ?? '', after.signature ?? '');
+  push('description', 'description', before.description ?? '', after.description ?? '');
+  edits.push(...diffTyped('field', before.fields ?? [], after.fields ?? []));
+  edits.push(...diffTyped('param', before.params ?? [], after.params ?? []));
+  push('type', 'returns', before.returns?.type ?? '', after.returns?.type ?? '');
+  push('description', 'returns', before.returns?.description ?? '', after.returns?.description ?? '');
+
+  return edits;
+}
+
+/**
+ * @doc diffTyped diffTyped
+ * @description Lists the type and description edits of named parameters or fields (added and removed ones are type edits)
+ * @param label The target prefix (param, field)
+ * @param before The old parameters or fields
+ * @param after The new parameters or fields
+ * @returns The edits, in new then removed order
+ */
+function diffTyped(label: string, before: DocParam[], after: DocParam[]): DocEdit[] {
+  const edits: DocEdit[] = [];
+  const oldByName = new Map(before.map(param => [param.name, param]));
+  const newNames = new Set(after.map(param => param.name));
+
+  for (const param of after) {
+    const old = oldByName.get(param.name);
+    const target = `${label} ${param.name}`;
+    if (!old) {
+      edits.push({ kind: 'type', target, before: '', after: param.type });
+      continue;
+    }
+    if (old.type !== param.type) {
+      edits.push({ kind: 'type', target, before: old.type, after: param.type });
+    }
+    if (old.description !== param.description) {
+      edits.push({ kind: 'description', target, before: old.description, after: param.description });
+    }
+  }
+
+  for (const param of before) {
+    if (!newNames.has(param.name)) {
+      edits.push({ kind: 'type', target: `${label} ${param.name}`, before: param.type, after: '' });
+    }
+  }
+
+  return edits;
+}
+
+/**
+ * @doc formatDocDiff formatDocDiff
+ * @description Formats the changes as a plain text report for release notes
+ * @description Type edits are marked with "!" and description-only edits with "~", breaking removals end with [breaking]
+ * @param diff The changes
+ * @returns The report, ending with a summary line
+ */
+export function formatDocDiff(diff: DocDiff): string {
+  const lines: string[] = [];
+  const sections: Array<[string, string, DocChange[]]> = [
+    ['Added', '+', diff.added],
+    ['Removed', '-', diff.removed],
+    ['Modified', '~', diff.modified],
+  ];
+
+  for (const [title, marker, changes] of sections) {
+    if (changes.length === 0) {
+      continue;
+    }
+
+    lines.push(`${title} (${changes.length}):`);
+    for (const change of changes) {
+      const breaking = change.breaking ? ' [breaking]' : '';
+      lines.push(`  ${marker} ${change.id} (${change.entryKind})${breaking}`);
+      lines.push(...change.edits.map(edit => `      ${formatEdit(edit)}`));
+    }
+    lines.push('');
+  }
+
+  const breakingCount = diff.removed.filter(change => change.breaking).length;
+  lines.push(lines.length === 0
+    ? 'No changes'
+    : `${diff.added.length} added, ${diff.removed.length} removed, ${diff.modified.length} modified, ` +
+      `${breakingCount} potential breaking change(s)`);
+
+  return lines.join('\n');
+}
+
+/**
+ * @doc formatEdit formatEdit
+ * @description Formats an edit: signature and type edits show both values, description edits only name their target
+ * @param edit The edit
+ * @returns The formatted edit
+ */
+function formatEdit(edit: DocEdit): string {
+  if (edit.kind === 'description') {
+    return `~ ${edit.target} description changed`;
+  }
+
+  const values = `${edit.before ? `\`${edit.before}\`` : '(none)'} -> ${edit.after ? `\`${edit.after}\`` : '(none)'}`;
+  return edit.kind === 'signature' ? `signature: ${values}` : `! type of ${edit.target}: ${values}`;
+}
+
+/**
+ * @doc createChange createChange
+ * @description Creates the change of an entry (only removals of public, non-internal entries are breaking)
+ * @param kind The change kind
+ * @param entry The entry (new version for modified entries)
+ * @param edits The edits of a modified entry
+ * @returns The change
+ */
+function createChange(kind: DocChangeKind, entry: DocEntry, edits: DocEdit[]): DocChange {
+  return {
+    kind,
+    id: entry.id,
+    name: entry.name,
+    entryKind: entry.kind ?? 'note',
+    breaking: kind === 'removed' && !entry.private && !entry.internal,
+    edits,
+  };
+}
+
+/**
+ * @doc compareIds compareIds
+ * @description Compares entry keys by code units, so reports don't depend on the locale
+ * @param a The first key
+ * @param b The second key
+ * @returns A negative number if a sorts first, 0 if equal, a positive number otherwise
+ */
+function compareIds(a: string, b: string): number {
+  return a < b ? -1 : a > b ? 1 : 0;
+}
@@ -0,0 +1,6 @@
+/**
+ * @doc differ Differ Module
+ * @description Reports the API changes between two versions of a documentation
+ */
+
+export * from './doc-differ';
@@ -5,6 +5,8 @@
 
 import { StandardocError, type CanonicalDoc, type DocBlock } from '../types/index';
 import { findDSLExpressions, type DSLExpression } from './dsl-parser';
+import { getDeprecation, getSeeReferences } from '../generator/index';
+import { findParentBlock, linkifyReferences, resolveReferenceId } from '../resolver/index';
 
 /**
  * @doc evaluateDSLExpression evaluateDSLExpression
@@ -48,6 +50,12 @@ export function evaluateDSLExpression(
     case 'each':
       return evaluateEach(block, expr.args || []);
 
+    case 'deprecated':
+      return evaluateDeprecated(block, doc);
+
+    case 'see':
+      return evaluateSee(block, doc);
+
     default:
       throw new StandardocError(`Unknown method: ${expr.method}`);
   }
@@ -205,6 +213,49 @@ function evaluateEach(block: DocBlock, args: string[]): string {
   return results.join('\n');
 }
 
+/**
+ * @doc evaluateDeprecated evaluateDeprecated
+ * @description Evaluates the deprecated() metamethod: doc.add:deprecated()
+ * @description Renders a Markdown banner, links @doc keys found in the message, and hints methods of deprecated types
+ * @param block The document block to query
+ * @param doc The canonical document, used to resolve references and the parent type
+ * @returns The deprecation banner, or an empty string if neither the block nor its parent is deprecated
+ */
+function evaluateDeprecated(block: DocBlock, doc: CanonicalDoc): string {
+  const deprecation = getDeprecation(block);
+
+  if (deprecation) {
+    const title = deprecation.since ? `Deprecated since ${deprecation.since}` : 'Deprecated';
+    const message = linkifyReferences(deprecation.message, doc);
+    return message ? `> **${title}**: ${message}` : `> **${title}**`;
+  }
+
+  const parent = findParentBlock(doc, block);
+  if (parent && getDeprecation(parent)) {
+    return `> *Deprecated: ${parent.label} is deprecated*`;
+  }
+
+  return '';
+}
+
+/**
+ * @doc evaluateSee evaluateSee
+ * @description Evaluates the see() metamethod: doc.add:see()
+ * @description Renders @see references as comma-separated Markdown links using the target label
+ * @description Unknown keys are rendered as plain text
+ * @param block The document block to query
+ * @param doc The canonical document, used to resolve references
+ * @returns The rendered references or an empty string if there are none
+ */
+function evaluateSee(block: DocBlock, doc: CanonicalDoc): string {
+  return getSeeReferences(block)
+    .map(reference => {
+      const id = resolveReferenceId(doc, reference);
+      return id ? `[${doc[`doc.${id}`].label}](#${id})` : reference;
+    })
+    .join(', ');
+}
+
 /**
  * @doc unquote unquote
  * @description Removes surrounding quotes from a string argument
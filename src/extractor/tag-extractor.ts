@@ -5,6 +5,11 @@
 
 import type { ParsedComment, ExtractedTag, DocBlock, DocMeta } from '../types/index';
 import { StandardocError } from '../types/index';
+import { detectItem } from '../analyzer/index';
+import { reportWarning } from '../diagnostics/index';
+import { isSemver, parseVersion, setInlineLinkPrefix } from '../resolver/index';
+import { LANGUAGE_FRONTENDS } from '../parser/index';
+import { statSync } from 'node:fs';
 import path from 'node:path';
 
 // Default doc tag name (can be overridden by config)
@@ -28,12 +33,159 @@ export function getDocTagName(): string {
   return DOC_TAG_NAME;
 }
 
-const TAG_REGEX = /^@(\w+(?:\.\w+)*)(?:\s+(.*))?$/;
+// Default tag prefix (can be overridden with --tag-prefix or the tagPrefix config key)
+let TAG_PREFIX = '@';
+let TAG_REGEX = buildTagRegex(TAG_PREFIX);
+
+/**
+ * @doc setTagPrefix setTagPrefix
+ * @description Sets the token starting every tag (e.g. "!" to read !doc, !param and {!link} inline links, leaving @tags to another tool)
+ * @param prefix The tag prefix (default: "@")
+ * @throws Error if the prefix is empty or contains whitespace
+ */
+export function setTagPrefix(prefix: string): void {
+  if (prefix === '' || /\s/.test(prefix)) {
+    throw new Error(`Invalid tag prefix: ${JSON.stringify(prefix)} (expected a non-empty token without whitespace)`);
+  }
+  TAG_PREFIX = prefix;
+  TAG_REGEX = buildTagRegex(prefix);
+  setInlineLinkPrefix(prefix);
+}
+
+/**
+ * @doc getTagPrefix getTagPrefix
+ * @description Gets the token starting every tag
+ * @returns The tag prefix
+ */
+export function getTagPrefix(): string {
+  return TAG_PREFIX;
+}
+
+/**
+ * @doc buildTagRegex buildTagRegex
+ * @description Builds the regex matching a tag line: prefix, dotted tag name and optional content
+ * @param prefix The tag prefix
+ * @returns The tag line regex
+ */
+function buildTagRegex(prefix: string): RegExp {
+  const escaped = prefix.replace(/[.*+?^${}()|[\]\\]/g, '\\$&');
+  return new RegExp(`^${escaped}(\\w+(?:\\.\\w+)*)(?:\\s+(.*))?$`);
+}
+
+const VERSION_REGEX = /^v?\d+(?:\.\d+)*(?:[-+][\w.]+)?$/;
+
+// Tags whose continuation lines are kept verbatim (code blocks)
+const RAW_CONTENT_TAGS = ['example'];
+
+// Tags stored as a single free text field
+const FREE_TEXT_TAGS = ['example', 'description', 'panics', 'errors', 'category', 'group'];
+
+// Tags understood by the generators (besides the doc tag)
+const KNOWN_TAGS = [
+  'description',
+  'param',
+  'field',
+  'returns',
+  'example',
+  'deprecated',
+  'see',
+  'generic',
+  'variant',
+  'since',
+  'category',
+  'group',
+  'throws',
+  'panics',
+  'errors',
+  'default',
+  'internal',
+  'alias',
+];
+
+// Minimum number of whitespace-separated fields of a well-formed tag
+const MIN_TAG_FIELDS: Record<string, number> = {
+  param: 2,
+  field: 2,
+  returns: 1,
+  see: 1,
+  generic: 2,
+  variant: 1,
+  since: 1,
+  category: 1,
+  group: 1,
+  throws: 1,
+  panics: 1,
+  errors: 1,
+  default: 1,
+  alias: 1,
+};
+
+// Rust primitive types, never the first word of a description (unlike number or string)
+const PRIMITIVE_TYPES = [
+  'i8', 'i16', 'i32', 'i64', 'i128', 'isize', 'u8', 'u16', 'u32', 'u64', 'u128', 'usize', 'f32', 'f64', 'bool', 'str',
+];
+
+// Type syntax: path (std::io::Error), reference (&str), generic (Vec<T>), array (T[], [u8; 4]) or pointer (char*)
+const TYPE_SYNTAX_REGEX = /^&|^\[.*\]$|::|^\w+<.*>$|\[\]$|\*$/;
+
+// Tags a @default value attaches to (the most recent one before it)
+const DEFAULT_TARGET_TAGS = ['param', 'field'];
+
+// Languages accepted as the first word of an @example tag line, besides the source languages (LANGUAGE_FRONTENDS)
+const EXAMPLE_LANGUAGES = [
+  'js', 'jsx', 'ts', 'tsx', 'py', 'rs', 'sh', 'bash', 'console',
+  'css', 'glsl', 'html', 'json', 'sql', 'toml', 'wgsl', 'yaml',
+];
+
+// Code fence delimiters, inside which example lines never start a new tag
+const FENCE_REGEX = /^(?:```|~~~)/;
+
+// Additional tags declared in the config (not reported as unknown)
+let customTags: string[] = [];
+
+/**
+ * @doc setCustomTags setCustomTags
+ * @description Sets the additional tag names accepted without an unknown tag warning
+ * @param tags The tag names, without @ (e.g., "author", "since")
+ */
+export function setCustomTags(tags: string[]): void {
+  customTags = tags;
+}
+
+/**
+ * @doc getCustomTags getCustomTags
+ * @description Gets the additional tag names set from config
+ * @returns The custom tag names
+ */
+export function getCustomTags(): string[] {
+  return customTags;
+}
+
+// Whether private items and fields are documented (--document-private)
+let documentPrivate = false;
+
+/**
+ * @doc setDocumentPrivate setDocumentPrivate
+ * @description Sets whether private items and fields are documented (--document-private)
+ * @param enabled True to document private items and fields
+ */
+export function setDocumentPrivate(enabled: boolean): void {
+  documentPrivate = enabled;
+}
+
+/**
+ * @doc getDocumentPrivate getDocumentPrivate
+ * @description Gets whether private items and fields are documented
+ * @returns True if private items and fields are documented
+ */
+export function getDocumentPrivate(): boolean {
+  return documentPrivate;
+}
 
 /**
  * @doc extractTags extractTags
  * @description Extracts all tags from a parsed comment
- * @description Handles multi-line tags (example, description) by collecting subsequent lines
+ * @description Lines that don't start a new tag are collected as continuations of the previous tag
  * @param comment The parsed comment to extract tags from
  * @returns Array of extracted tags with their content and line numbers
  */
@@ -41,8 +193,6 @@ export function extractTags(comment: ParsedComment): ExtractedTag[] {
   const tags: ExtractedTag[] = [];
   const lines = comment.content.split('\n');
 
-  const multiLineTags = ['example', 'description'];
-
   let i = 0;
   while (i < lines.length) {
     const line = lines[i].trim();
@@ -51,66 +201,37 @@ export function extractTags(comment: ParsedComment): ExtractedTag[] {
     if (match) {
       const [, tagName, content = ''] = match;
 
-      if (multiLineTags.includes(tagName)) {
-        const multiLineContent: string[] = [];
-        let j = i + 1;
-
-        while (j < lines.length) {
-          const nextLine = lines[j].trim();
-
-          if (nextLine.match(TAG_REGEX)) {
-            break;
-          }
-
-          multiLineContent.push(lines[j]);
-          j++;
-        }
-
-        const fullContent = content + (multiLineContent.length > 0 ? '\n' + multiLineContent.join('\n') : '');
-
-        tags.push({
-          name: tagName,
-          args: [],
-          content: fullContent.trim(),
-          line: comment.line + i,
-        });
-
-        i = j;
-      } else {
-        // Tag single line
-        // For doc tag, we want to keep the label with spaces
-        // For other tags, we split by space
-        let args: string[];
-        if (tagName === DOC_TAG_NAME) {
-          // For @doc (or custom tag), we keep the content as is and split intelligently
-          // Format: @doc key label (label can have spaces)
-          // We split only the first word (key), the rest is the label
-          const trimmed = content.trim();
-          if (trimmed) {
-            const firstSpace = trimmed.indexOf(' ');
-            if (firstSpace === -1) {
-              // No space = only key
-              args = [trimmed];
-            } else {
-              // First word = key, rest = label
-              args = [trimmed.slice(0, firstSpace), trimmed.slice(firstSpace + 1)];
-            }
-          } else {
-            args = [];
-          }
-        } else {
-          args = content ? content.split(/\s+/).filter(arg => arg.length > 0) : [];
-        }
-
+      if (tagName === DOC_TAG_NAME) {
         tags.push({
           name: tagName,
-          args,
+          args: splitTagArgs(tagName, content),
           content,
-          line: comment.line + i,
+          line: comment.contentLine + i,
+          column: getTagColumn(comment, i, tagName),
         });
 
         i++;
+        continue;
       }
+
+      // Subsequent lines that don't start a new tag are continuations of this tag
+      const continues = createContinuationMatcher(tagName, content);
+      let j = i + 1;
+      while (j < lines.length && continues(lines[j])) {
+        j++;
+      }
+
+      const fullContent = joinTagContent(tagName, content, lines.slice(i + 1, j));
+
+      tags.push({
+        name: tagName,
+        args: splitTagArgs(tagName, fullContent),
+        content: fullContent,
+        line: comment.contentLine + i,
+        column: getTagColumn(comment, i, tagName),
+      });
+
+      i = j;
     } else {
       i++;
     }
@@ -119,67 +240,437 @@ export function extractTags(comment: ParsedComment): ExtractedTag[] {
   return tags;
 }
 
+/**
+ * @doc inferParamTypes inferParamTypes
+ * @description Fills the type of @param tags written without one (@param name description) from the detected signature
+ * @description A second field not found in the signature type is taken as the start of the description, unless it looks like a type (see looksLikeType): then it is a mismatch, reported as a warning
+ * @description Types that can't be inferred are set to "unknown" with a warning
+ * @param block The doc block, with its detected item if any
+ * @param tags The extracted @param tags, in the order of block.param, to report warnings at their line
+ */
+function inferParamTypes(block: DocBlock, tags: ExtractedTag[] = []): void {
+  const params = block.param;
+  if (!Array.isArray(params)) {
+    return;
+  }
+
+  block.param = params.map((fields, index) => {
+    const [name = '', type = '', ...description] = fields;
+    const signatureType = block.item?.params?.find(param => param.name === name)?.type;
+    const tag = tags[index];
+
+    if (signatureType) {
+      return matchSignatureType(block, 'param', fields, signatureType, tag);
+    }
+
+    const signatureParams = block.item?.params;
+    if (signatureParams && !signatureParams.some(param => param.name === name)) {
+      reportWarning(
+        `cannot infer type of ${TAG_PREFIX}param ${name} (no parameter '${name}' in signature of ${block.item?.name})`,
+        block.meta.path,
+        tag?.line ?? block.meta.line,
+        tag?.column
+      );
+      return [name, 'unknown', [type, ...description].filter(Boolean).join(' ')];
+    }
+
+    // Without a signature type, only a full @param name type description is typed
+    if (fields.length >= 3) {
+      return fields;
+    }
+
+    const reason = signatureParams ? 'untyped in signature' : 'no signature found';
+    reportWarning(
+      `cannot infer type of ${TAG_PREFIX}param ${name} (${reason})`,
+      block.meta.path,
+      tag?.line ?? block.meta.line,
+      tag?.column
+    );
+    return [name, 'unknown', type];
+  });
+}
+
+/**
+ * @doc inferFieldTypes inferFieldTypes
+ * @description Fills the type of @field tags written without one (@field name description) from the parsed struct fields, like @param types
+ * @description Documenting a private field reports a warning, unless private items are documented (--document-private)
+ * @param block The doc block, with its detected item if any
+ * @param tags The extracted @field tags, in the order of block.field, to report warnings at their line
+ */
+function inferFieldTypes(block: DocBlock, tags: ExtractedTag[] = []): void {
+  const tagFields = block.field;
+  if (!Array.isArray(tagFields)) {
+    return;
+  }
+
+  const structFields = block.item?.fields;
+
+  block.field = tagFields.map((fields, index) => {
+    const [name = '', type = '', ...description] = fields;
+    const structField = structFields?.find(field => field.name === name);
+    const tag = tags[index];
+
+    if (structField) {
+      if (!structField.public && !documentPrivate) {
+        reportWarning(
+          `${TAG_PREFIX}field ${name} documents a private field of ${block.item?.name} (use --document-private)`,
+          block.meta.path,
+          tag?.line ?? block.meta.line,
+          tag?.column
+        );
+      }
+      return matchSignatureType(block, 'field', fields, structField.type, tag);
+    }
+
+    if (structFields) {
+      reportWarning(
+        `cannot infer type of ${TAG_PREFIX}field ${name} (no field '${name}' in ${block.item?.name})`,
+        block.meta.path,
+        tag?.line ?? block.meta.line,
+        tag?.column
+      );
+      return [name, 'unknown', [type, ...description].filter(Boolean).join(' ')];
+    }
+
+    // Without a struct definition, only a full @field name type description is typed
+    if (fields.length >= 3) {
+      return fields;
+    }
+
+    reportWarning(
+      `cannot infer type of ${TAG_PREFIX}field ${name} (no struct definition found)`,
+      block.meta.path,
+      tag?.line ?? block.meta.line,
+      tag?.column
+    );
+    return [name, 'unknown', type];
+  });
+}
+
+/**
+ * @doc matchSignatureType matchSignatureType
+ * @description Types the fields of a @param or @field tag with the type read from the source
+ * @description A matching documented type is kept; a mismatching one that looks like a type is replaced with a warning, any other second field starts the description
+ * @param block The doc block
+ * @param tagName The tag name (param or field)
+ * @param fields The tag fields: name, documented type or first description word, description
+ * @param signatureType The type read from the signature or struct definition
+ * @param tag The extracted tag, to report a mismatch at its line (the block line without it)
+ * @returns The typed tag fields
+ */
+function matchSignatureType(
+  block: DocBlock,
+  tagName: string,
+  fields: string[],
+  signatureType: string,
+  tag?: ExtractedTag
+): string[] {
+  const [name = '', type = '', ...description] = fields;
+  if (type && isTypeOf(type, signatureType)) {
+    return fields;
+  }
+
+  if (type && looksLikeType(type)) {
+    reportWarning(
+      `documented type '${type}' of ${TAG_PREFIX}${tagName} ${name} does not match signature type '${signatureType}'`,
+      block.meta.path,
+      tag?.line ?? block.meta.line,
+      tag?.column
+    );
+    return [name, signatureType, description.join(' ')];
+  }
+
+  return [name, signatureType, [type, ...description].filter(Boolean).join(' ')];
+}
+
+/**
+ * @doc looksLikeType looksLikeType
+ * @description Tells whether a word can only be a type, not the start of a description: a primitive type or a type path, reference, pointer, array or generic
+ * @param word The second field of a @param or @field tag
+ * @returns True if the word looks like a type
+ */
+function looksLikeType(word: string): boolean {
+  return PRIMITIVE_TYPES.includes(word) || TYPE_SYNTAX_REGEX.test(word);
+}
+
+/**
+ * @doc isTypeOf isTypeOf
+ * @description Checks whether a documented type matches a signature type, whole or as a part (Vector3 in const Vector3&)
+ * @param type The documented type (single field)
+ * @param signatureType The type read from the signature
+ * @returns True if the documented type appears in the signature type
+ */
+function isTypeOf(type: string, signatureType: string): boolean {
+  const normalized = signatureType.replace(/\s+/g, ' ').trim();
+  const escaped = type.replace(/[.*+?^${}()|[\]\\]/g, '\\$&');
+  return normalized === type || new RegExp(`(?<![\\w$])${escaped}(?![\\w$])`).test(normalized);
+}
+
+/**
+ * @doc createContinuationMatcher createContinuationMatcher
+ * @description Creates a predicate telling whether a line continues the current tag
+ * @description Lines starting with @ inside a code fence of an example (e.g. decorators) don't start a new tag
+ * @param tagName The tag name
+ * @param firstLine The content on the tag line
+ * @returns A stateful predicate to call on each following line, in order
+ */
+function createContinuationMatcher(tagName: string, firstLine: string): (line: string) => boolean {
+  const isRaw = RAW_CONTENT_TAGS.includes(tagName);
+  let inFence = isRaw && FENCE_REGEX.test(firstLine.trim());
+
+  return (line: string) => {
+    const trimmed = line.trim();
+    if (!inFence && TAG_REGEX.test(trimmed)) {
+      return false;
+    }
+
+    if (isRaw && FENCE_REGEX.test(trimmed)) {
+      inFence = !inFence;
+    }
+    return true;
+  };
+}
+
+/**
+ * @doc validateTag validateTag
+ * @description Reports a warning for an unknown tag (e.g. a typo like @paramm), a malformed known tag or a @since version that isn't semver
+ * @param tag The extracted tag
+ * @param filePath The file path reported in the warning
+ */
+function validateTag(tag: ExtractedTag, filePath: string): void {
+  if (!KNOWN_TAGS.includes(tag.name) && !customTags.includes(tag.name)) {
+    reportWarning(`unknown tag '${TAG_PREFIX}${tag.name}'`, filePath, tag.line, tag.column);
+    return;
+  }
+
+  const minFields = MIN_TAG_FIELDS[tag.name];
+  const fieldCount = tag.content.split(/\s+/).filter(field => field.length > 0).length;
+  if (minFields !== undefined && fieldCount < minFields) {
+    reportWarning(
+      `malformed tag '${TAG_PREFIX}${tag.name}' (expected at least ${minFields} field(s), found ${fieldCount})`,
+      filePath,
+      tag.line,
+      tag.column
+    );
+  }
+
+  const version = tag.content.trim();
+  if (tag.name === 'since' && version && !isSemver(version)) {
+    const hint = parseVersion(version) ? '' : ', ignored by --min-version';
+    reportWarning(`'${TAG_PREFIX}since ${version}' is not a valid semver version${hint}`, filePath, tag.line, tag.column);
+  }
+}
+
+/**
+ * @doc getExampleHeader getExampleHeader
+ * @description Reads the tag line of an @example tag: an optional language (lowercase, e.g. c, js), then the title, unless the rest opens the code fence
+ * @param firstLine The content on the tag line
+ * @returns The language and title (empty if absent), and the content of the tag line that belongs to the code
+ */
+function getExampleHeader(firstLine: string): { language: string; title: string; code: string } {
+  let rest = firstLine.trim();
+  const word = rest.split(/\s+/, 1)[0];
+  const language = Object.hasOwn(LANGUAGE_FRONTENDS, word) || EXAMPLE_LANGUAGES.includes(word) ? word : '';
+  if (language) {
+    rest = rest.slice(word.length).trim();
+  }
+
+  return FENCE_REGEX.test(rest)
+    ? { language, title: '', code: rest }
+    : { language, title: rest, code: '' };
+}
+
+/**
+ * @doc joinTagContent joinTagContent
+ * @description Joins the first line of a tag with its continuation lines
+ * @description Raw content tags (example) keep their lines verbatim, other tags are joined with a single space
+ * @param tagName The tag name
+ * @param firstLine The content on the tag line
+ * @param continuation The continuation lines following the tag line
+ * @returns The full tag content
+ */
+function joinTagContent(tagName: string, firstLine: string, continuation: string[]): string {
+  if (RAW_CONTENT_TAGS.includes(tagName)) {
+    const fullContent = firstLine + (continuation.length > 0 ? '\n' + continuation.join('\n') : '');
+    return fullContent.trim();
+  }
+
+  return [firstLine, ...continuation]
+    .map(line => line.trim())
+    .filter(line => line.length > 0)
+    .join(' ');
+}
+
+/**
+ * @doc splitTagArgs splitTagArgs
+ * @description Splits tag content into arguments
+ * @description For @doc (or custom tag), splits only the first word (key), the rest is the label
+ * @description Free text tags (example, description) have no arguments
+ * @param tagName The tag name
+ * @param content The tag content
+ * @returns Array of tag arguments
+ */
+function splitTagArgs(tagName: string, content: string): string[] {
+  if (tagName === DOC_TAG_NAME) {
+    // Format: @doc key label (label can have spaces)
+    const trimmed = content.trim();
+    if (!trimmed) {
+      return [];
+    }
+
+    const firstSpace = trimmed.indexOf(' ');
+    if (firstSpace === -1) {
+      // No space = only key
+      return [trimmed];
+    }
+
+    // First word = key, rest = label
+    return [trimmed.slice(0, firstSpace), trimmed.slice(firstSpace + 1)];
+  }
+
+  if (FREE_TEXT_TAGS.includes(tagName)) {
+    return [];
+  }
+
+  return content ? content.split(/\s+/).filter(arg => arg.length > 0) : [];
+}
+
 /**
  * @doc extractDocBlocks extractDocBlocks
  * @description Extracts documentable blocks from a list of comments
  * @description A documentable block starts with @doc (or custom tag) and ends at the next @doc or end of consecutive comments
+ * @description When the source content is given, the item following each block is detected and attached
  * @param comments Array of parsed comments from a file
  * @param filePath The file path where comments were found
  * @param workspaceRoot The workspace root directory for relative paths
+ * @param source Optional source content of the file, used for item detection
  * @returns Array of extracted documentable blocks
  */
 export function extractDocBlocks(
   comments: ParsedComment[],
   filePath: string,
-  workspaceRoot: string
+  workspaceRoot: string,
+  source?: string
 ): DocBlock[] {
   const blocks: DocBlock[] = [];
+  const sourceLines = source !== undefined ? source.split(/\r?\n/) : null;
   let currentBlock: {
     initTag: ExtractedTag;
     comments: ParsedComment[];
     startLine: number;
+    startOffset: number;
+    endLine: number;
+    contiguous: boolean;
   } | null = null;
 
-  for (const comment of comments) {
-    const tags = extractTags(comment);
-    const initTag = tags.find(tag => tag.name === DOC_TAG_NAME);
+  const flushBlock = () => {
+    if (!currentBlock) {
+      return;
+    }
 
-    if (initTag) {
-      if (currentBlock) {
-        const block = buildDocBlock(
-          currentBlock.initTag,
-          currentBlock.comments,
+    const tags = new Map<string, ExtractedTag[]>();
+    const block = buildDocBlock(
+      currentBlock.initTag,
+      currentBlock.comments,
+      filePath,
+      workspaceRoot,
+      currentBlock.startLine,
+      currentBlock.startOffset,
+      tags
+    );
+
+    // Inner doc comments (//!, /*!) document the enclosing module, never the item that follows
+    if (sourceLines && !isInnerDocComment(currentBlock.comments[0])) {
+      const item = detectItem(sourceLines, currentBlock.endLine, path.extname(filePath));
+      if (item) {
+        block.item = item;
+      }
+    }
+
+    // Without a key, the block is named after the documented item (key generated at merge time)
+    if (block.meta.generatedKey) {
+      if (!block.item) {
+        throw new StandardocError(
+          `${TAG_PREFIX}${DOC_TAG_NAME} without key must be followed by a documentable item`,
           filePath,
-          workspaceRoot,
           currentBlock.startLine
         );
-        blocks.push(block);
       }
+      block.label = block.item.name;
+    }
+
+    inferParamTypes(block, tags.get('param'));
+    inferFieldTypes(block, tags.get('field'));
+
+    blocks.push(block);
+  };
+
+  for (const comment of comments) {
+    const tags = extractTags(comment);
+    const initTag = tags.find(tag => tag.name === DOC_TAG_NAME);
+
+    if (initTag) {
+      flushBlock();
 
       currentBlock = {
         initTag,
         comments: [comment],
         startLine: comment.line,
+        startOffset: comment.offset,
+        endLine: getCommentEndLine(comment),
+        contiguous: true,
       };
     } else if (currentBlock) {
       currentBlock.comments.push(comment);
+
+      // Only comments directly following the block delimit where the documented item starts
+      if (currentBlock.contiguous && comment.line === currentBlock.endLine + 1) {
+        currentBlock.endLine = getCommentEndLine(comment);
+      } else {
+        currentBlock.contiguous = false;
+      }
     }
   }
 
-  if (currentBlock) {
-    const block = buildDocBlock(
-      currentBlock.initTag,
-      currentBlock.comments,
-      filePath,
-      workspaceRoot,
-      currentBlock.startLine
-    );
-    blocks.push(block);
-  }
+  flushBlock();
 
   return blocks;
 }
 
+/**
+ * @doc isInnerDocComment isInnerDocComment
+ * @description Checks whether a comment is a Rust inner doc comment (//! or /*!)
+ * @param comment The parsed comment
+ * @returns True for an inner doc comment
+ */
+function isInnerDocComment(comment: ParsedComment): boolean {
+  return /^\s*(?:\/\/!|\/\*!)/.test(comment.raw);
+}
+
+/**
+ * @doc getCommentEndLine getCommentEndLine
+ * @description Gets the last line number of a comment (multi-line comments span several lines)
+ * @param comment The parsed comment
+ * @returns The 1-based line number of the last line of the comment
+ */
+function getCommentEndLine(comment: ParsedComment): number {
+  return comment.line + comment.raw.split('\n').length - 1;
+}
+
+/**
+ * @doc getTagColumn getTagColumn
+ * @description Finds the column of a tag in the source: the position of its prefix on the raw comment line
+ * @param comment The comment holding the tag
+ * @param lineInComment The index of the tag line in the comment content
+ * @param tagName The tag name
+ * @returns The 1-based column of the tag, or the comment column if the raw line doesn't contain it
+ */
+function getTagColumn(comment: ParsedComment, lineInComment: number, tagName: string): number {
+  const rawLine = comment.raw.split('\n')[comment.contentLine - comment.line + lineInComment] ?? '';
+  const index = rawLine.indexOf(`${TAG_PREFIX}${tagName}`);
+  return index === -1 ? comment.column : index + 1;
+}
+
 /**
  * @doc buildDocBlock buildDocBlock
  * @description Builds a DocBlock from a @doc (or custom) tag and its associated comments
@@ -189,25 +680,22 @@ export function extractDocBlocks(
  * @param filePath The file path where the block was found
  * @param workspaceRoot The workspace root for relative paths
  * @param startLine The starting line number of the block
+ * @param startOffset The UTF-8 byte offset of the block start
+ * @param tags Filled with the extracted tags by name, in the order of their fields in the block
+ * @description A bare @doc (no key) marks the block for a generated key (meta.generatedKey)
  * @returns A complete DocBlock with all extracted tags and metadata
- * @throws StandardocError if @doc tag is invalid or has wrong number of arguments
  */
 function buildDocBlock(
   initTag: ExtractedTag,
   comments: ParsedComment[],
   filePath: string,
   workspaceRoot: string,
-  startLine: number
+  startLine: number,
+  startOffset: number,
+  tags: Map<string, ExtractedTag[]> = new Map()
 ): DocBlock {
-  if (initTag.args.length < 1) {
-    throw new StandardocError(
-      `@${DOC_TAG_NAME} requires at least 1 argument: <key> [label]`,
-      filePath,
-      initTag.line
-    );
-  }
-
-  const key = initTag.args[0];
+  // A bare @doc has no key: it is generated from the item name once the item is detected
+  const key = initTag.args[0] ?? '';
   // If no label, use key as label
   // Otherwise, join all remaining args as label (to support spaces)
   const label = initTag.args.length > 1
@@ -218,30 +706,40 @@ function buildDocBlock(
   const meta: DocMeta = {
     path: relativePath,
     line: startLine,
+    offset: startOffset,
     file: path.basename(filePath),
     ext: path.extname(filePath),
     lastEdit: getLastEdit(filePath),
   };
 
   const allTags = new Map<string, string[][]>();
-  const multiLineTags = ['example', 'description'];
 
-  const allCommentLines: Array<{ line: string; commentIndex: number; lineInComment: number }> = [];
+  const allCommentLines: Array<{ line: string; commentIndex: number; lineInComment: number; run: number }> = [];
+  let run = 0;
   for (let i = 0; i < comments.length; i++) {
     const comment = comments[i];
+    // A new run starts when a comment doesn't directly follow the previous one
+    if (i > 0 && comment.line !== getCommentEndLine(comments[i - 1]) + 1) {
+      run++;
+    }
+
     const lines = comment.content.split('\n');
     for (let j = 0; j < lines.length; j++) {
       allCommentLines.push({
         line: lines[j],
         commentIndex: i,
         lineInComment: j,
+        run,
       });
     }
   }
 
+  // Name of the most recent @param, the target of a following @default
+  let defaultTarget = '';
+
   let i = 0;
   while (i < allCommentLines.length) {
-    const { line: rawLine } = allCommentLines[i];
+    const { line: rawLine, commentIndex, lineInComment, run: tagRun } = allCommentLines[i];
     const line = rawLine.trim();
     const match = line.match(TAG_REGEX);
 
@@ -253,77 +751,59 @@ function buildDocBlock(
         continue;
       }
 
-      if (multiLineTags.includes(tagName)) {
-        const multiLineContent: string[] = [];
-        let j = i + 1;
-
-        while (j < allCommentLines.length) {
-          const { line: nextRawLine } = allCommentLines[j];
-          const nextLine = nextRawLine.trim();
-
-          if (nextLine.match(TAG_REGEX)) {
-            break;
-          }
-
-          multiLineContent.push(allCommentLines[j].line);
-          j++;
-        }
-
-        const fullContent = content + (multiLineContent.length > 0 ? '\n' + multiLineContent.join('\n') : '');
-
-        const tag: ExtractedTag = {
-          name: tagName,
-          args: [],
-          content: fullContent.trim(),
-          line: comments[allCommentLines[i].commentIndex].line + allCommentLines[i].lineInComment,
-        };
-
-        const fields = parseTagContent(tag);
+      // Continuation lines stop at the next tag or at the end of consecutive comments
+      const continues = createContinuationMatcher(tagName, content);
+      let j = i + 1;
+      while (
+        j < allCommentLines.length &&
+        allCommentLines[j].run === tagRun &&
+        continues(allCommentLines[j].line)
+      ) {
+        j++;
+      }
 
-        if (!allTags.has(tagName)) {
-          allTags.set(tagName, []);
-        }
+      // @example [language] [title]: the text on the tag line is the title, the code block follows
+      const example = tagName === 'example' ? getExampleHeader(content) : null;
+      const fullContent = joinTagContent(
+        tagName,
+        example ? example.code : content,
+        allCommentLines.slice(i + 1, j).map(entry => entry.line)
+      );
+
+      const tag: ExtractedTag = {
+        name: tagName,
+        args: splitTagArgs(tagName, fullContent),
+        content: fullContent,
+        line: comments[commentIndex].contentLine + lineInComment,
+        column: getTagColumn(comments[commentIndex], lineInComment, tagName),
+      };
 
-        allTags.get(tagName)!.push(fields);
+      validateTag(tag, relativePath);
 
-        i = j;
+      let fields: string[];
+      if (example) {
+        // Format: [code, title, language]
+        fields = [fullContent, example.title, example.language];
+      } else if (tagName === 'default') {
+        // Format: [target, value], the value is kept as written (e.g. Vec::new()); target is empty without a preceding @param
+        fields = [defaultTarget, fullContent];
       } else {
-        // Tag simple ligne
-        // Pour @doc (ou custom), on veut garder le label avec espaces
-        let args: string[];
-        if (tagName === DOC_TAG_NAME) {
-          const trimmed = content.trim();
-          if (trimmed) {
-            const firstSpace = trimmed.indexOf(' ');
-            if (firstSpace === -1) {
-              args = [trimmed];
-            } else {
-              args = [trimmed.slice(0, firstSpace), trimmed.slice(firstSpace + 1)];
-            }
-          } else {
-            args = [];
-          }
-        } else {
-          args = content ? content.split(/\s+/).filter(arg => arg.length > 0) : [];
-        }
-
-        const tag: ExtractedTag = {
-          name: tagName,
-          args,
-          content,
-          line: comments[allCommentLines[i].commentIndex].line + allCommentLines[i].lineInComment,
-        };
+        fields = parseTagContent(tag);
+      }
 
-        const fields = parseTagContent(tag);
+      if (DEFAULT_TARGET_TAGS.includes(tagName)) {
+        defaultTarget = tag.args[0] ?? '';
+      }
 
-        if (!allTags.has(tagName)) {
-          allTags.set(tagName, []);
-        }
+      if (!allTags.has(tagName)) {
+        allTags.set(tagName, []);
+        tags.set(tagName, []);
+      }
 
-        allTags.get(tagName)!.push(fields);
+      allTags.get(tagName)!.push(fields);
+      tags.get(tagName)!.push(tag);
 
-        i++;
-      }
+      i = j;
     } else {
       i++;
     }
@@ -331,10 +811,9 @@ function buildDocBlock(
 
   const block: DocBlock = {
     label,
-    meta: {
-      ...meta,
-      key,
-    } as DocMeta & { key: string },
+    meta: key
+      ? { ...meta, key }
+      : { ...meta, generatedKey: true },
   };
 
   for (const [tagName, tagData] of allTags.entries()) {
@@ -349,19 +828,18 @@ function buildDocBlock(
  * @description Parses tag content into fields
  * @description For most tags, splits by space but keeps description as single string
  * @description For special tags (example), keeps raw content
+ * @description For @deprecated, returns [version, message] (version is empty if omitted)
  * @param tag The extracted tag to parse
  * @returns Array of parsed fields
  */
 function parseTagContent(tag: ExtractedTag): string[] {
-  const rawContentTags = ['example', 'description'];
-
-  if (rawContentTags.includes(tag.name)) {
+  if (FREE_TEXT_TAGS.includes(tag.name)) {
     return [tag.content];
   }
 
   const parts = tag.content.split(/\s+/);
 
-  if (tag.name === 'param') {
+  if (tag.name === 'param' || tag.name === 'field') {
     if (parts.length >= 3) {
       const name = parts[0];
       const type = parts[1];
@@ -369,6 +847,13 @@ function parseTagContent(tag: ExtractedTag): string[] {
       return [name, type, description];
     }
     return parts;
+  } else if (tag.name === 'generic' || tag.name === 'variant') {
+    if (parts.length >= 2) {
+      const name = parts[0];
+      const description = parts.slice(1).join(' ');
+      return [name, description];
+    }
+    return parts;
   } else if (tag.name === 'returns') {
     if (parts.length >= 2) {
       const type = parts[0];
@@ -376,6 +861,13 @@ function parseTagContent(tag: ExtractedTag): string[] {
       return [type, description];
     }
     return parts;
+  } else if (tag.name === 'deprecated') {
+    // Format: @deprecated [version] [message], version is optional
+    const [first = '', ...rest] = parts.filter(part => part.length > 0);
+    if (VERSION_REGEX.test(first)) {
+      return [first, rest.join(' ')];
+    }
+    return ['', tag.content.trim()];
   }
 
   return parts;
@@ -385,14 +877,12 @@ function parseTagContent(tag: ExtractedTag): string[] {
  * @doc getLastEdit getLastEdit
  * @description Gets the last modification date of a file
  * @param filePath The file path to check
- * @returns ISO string of the last modification time
+ * @returns ISO string of the last modification time, or an empty string if the file can't be read (in-memory sources), so output never depends on the current time
  */
 function getLastEdit(filePath: string): string {
   try {
-    const fs = require('node:fs');
-    const stats = fs.statSync(filePath);
-    return stats.mtime.toISOString();
+    return statSync(filePath).mtime.toISOString();
   } catch {
-    return new Date().toISOString();
+    return '';
   }
 }
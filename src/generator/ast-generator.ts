@@ -0,0 +1,357 @@
+/**
+ * @doc ast_generator AST JSON Generator
+ * @description Transforms the canonical document into a structured array of DocEntry objects
+ */
+
+import type {
+  CanonicalDoc,
+  DocBlock,
+  DocDeprecation,
+  DocEntry,
+  DocField,
+  DocGeneric,
+  DocParam,
+  DocReturn,
+  DocVariant,
+  TagData,
+} from '../types/index';
+import { findParentBlock, getAliases, getModulePath } from '../resolver/index';
+import { isPublicVisibility } from '../analyzer/index';
+import { getLanguage } from '../parser/index';
+
+/**
+ * @doc buildDocEntries buildDocEntries
+ * @description Builds structured entries from a canonical document
+ * @description Entries keep the order of the canonical document
+ * @param doc The canonical document to transform
+ * @returns Array of structured doc entries
+ */
+export function buildDocEntries(doc: CanonicalDoc): DocEntry[] {
+  return Object.entries(doc).map(([docKey, block]) =>
+    buildDocEntry(docKey.slice('doc.'.length), block, findParentBlock(doc, block))
+  );
+}
+
+/**
+ * @doc buildDocEntry buildDocEntry
+ * @description Builds a single structured entry from a DocBlock
+ * @description Field order is fixed so the serialized schema stays stable
+ * @param id The entry id (key of the @doc tag)
+ * @param block The documentable block to transform
+ * @param parent The documented type owning the block, if any
+ * @returns The structured doc entry
+ */
+export function buildDocEntry(id: string, block: DocBlock, parent: DocBlock | null = null): DocEntry {
+  return {
+    id,
+    name: block.label,
+    kind: block.item?.kind ?? 'note',
+    module: getModulePath(block),
+    signature: block.item?.signature ?? null,
+    description: getDescription(block),
+    summary: getSummary(getDescription(block)),
+    generics: getGenerics(block, parent),
+    params: getParams(block),
+    fields: getFields(block),
+    variants: getVariants(block),
+    methods: block.item?.methods ?? [],
+    implements: block.item?.trait ?? null,
+    receiver: block.item?.receiver ?? null,
+    aliases: getAliases(block),
+    returns: buildReturn(getTagData(block, 'returns')),
+    errors: getTagTexts(block, 'errors'),
+    panics: getTagTexts(block, 'panics'),
+    examples: getTagData(block, 'example').map(([content = '', title = '', language = '']) => ({
+      title: title || null,
+      content,
+      language: getExampleLanguage(content, language, block),
+    })),
+    deprecated: getDeprecation(block),
+    since: getTagTexts(block, 'since')[0] ?? null,
+    category: getCategory(block),
+    group: getGroup(block),
+    cfg: block.item?.cfg ?? null,
+    private: !isPublicVisibility(block.item?.visibility),
+    internal: isInternal(block, parent),
+    see: getSeeReferences(block),
+    span: { line: block.meta.line, offset: block.meta.offset, itemLine: block.item?.line ?? null },
+  };
+}
+
+/**
+ * @doc SUMMARY_MAX_LENGTH SUMMARY_MAX_LENGTH
+ * @description Maximum length of an entry summary (longer sentences are cut at a word boundary)
+ */
+export const SUMMARY_MAX_LENGTH = 120;
+
+// Words that end with a period without ending the sentence (compared in lowercase, without the final period)
+const ABBREVIATIONS = ['e.g', 'eg', 'i.e', 'ie', 'etc', 'vs', 'cf', 'approx', 'resp', 'mr', 'mrs', 'ms', 'dr', 'st'];
+
+/**
+ * @doc getDescription getDescription
+ * @description Gets the description of a block: its @description tags, one per line
+ * @param block The documentable block
+ * @returns The description, empty if the block has none
+ */
+export function getDescription(block: DocBlock): string {
+  return getTagData(block, 'description')
+    .map(fields => fields[0] || '')
+    .join('\n');
+}
+
+/**
+ * @doc getSummary getSummary
+ * @description Gets the summary of a description: its first sentence, up to the first . ! or ? followed by whitespace
+ * @description Periods of abbreviations (e.g., i.e., etc., No. before a number) and initials (J. Smith) don't end the sentence; without a sentence end, the whole first line is the summary
+ * @param description The description
+ * @returns The summary, at most SUMMARY_MAX_LENGTH characters (cut with an ellipsis), empty if the description is empty
+ */
+export function getSummary(description: string): string {
+  const line = description.split('\n').map(text => text.trim()).find(text => text.length > 0) ?? '';
+  let summary = line;
+
+  for (const match of line.matchAll(/[.!?](?=\s|$)/g)) {
+    const index = match.index ?? 0;
+    const word = (line.slice(0, index).match(/\S*$/)?.[0] ?? '').replace(/^[^\w]+/, '');
+    const next = line.slice(index + 1).trimStart();
+    const isInitial = /^\p{Lu}$/u.test(word) && /^\p{Lu}/u.test(next);
+    const isNumberSign = word.toLowerCase() === 'no' && /^\d/.test(next);
+    if (match[0] === '.' && (ABBREVIATIONS.includes(word.toLowerCase()) || isInitial || isNumberSign)) {
+      continue;
+    }
+    summary = line.slice(0, index + 1);
+    break;
+  }
+
+  if (summary.length <= SUMMARY_MAX_LENGTH) {
+    return summary;
+  }
+
+  const cut = summary.slice(0, SUMMARY_MAX_LENGTH - 1);
+  const lastSpace = cut.lastIndexOf(' ');
+  return `${(lastSpace > 0 ? cut.slice(0, lastSpace) : cut).trimEnd()}…`;
+}
+
+/**
+ * @doc getParams getParams
+ * @description Gets the parameters documented by the @param tags of a block, with the value of their @default tag
+ * @param block The documentable block
+ * @returns The parameters, in tag order
+ */
+export function getParams(block: DocBlock): DocParam[] {
+  return getTypedTags(block, 'param');
+}
+
+/**
+ * @doc getFields getFields
+ * @description Gets the struct fields documented by the @field tags of a block, with the value of their @default tag
+ * @param block The documentable block
+ * @returns The fields, in tag order
+ */
+export function getFields(block: DocBlock): DocField[] {
+  return getTypedTags(block, 'field');
+}
+
+/**
+ * @doc getTypedTags getTypedTags
+ * @description Reads name, type, description tags (@param, @field) with the value of the @default tag attached to each name
+ * @param block The documentable block
+ * @param tagName The tag name
+ * @returns The documented values, in tag order
+ */
+function getTypedTags(block: DocBlock, tagName: string): DocParam[] {
+  const defaults = new Map(getTagData(block, 'default').map(([target = '', value = '']) => [target, value]));
+
+  return getTagData(block, tagName).map(([name = '', type = '', description = '']) => {
+    const value = name ? defaults.get(name) : undefined;
+    return value !== undefined ? { name, type, description, default: value } : { name, type, description };
+  });
+}
+
+/**
+ * @doc getGenerics getGenerics
+ * @description Gets the type parameters documented by the @generic tags of a block
+ * @description Type parameters documented identically on the parent type are left out
+ * @param block The documentable block
+ * @param parent The documented type owning the block, if any
+ * @returns The type parameters, in tag order
+ */
+export function getGenerics(block: DocBlock, parent: DocBlock | null = null): DocGeneric[] {
+  const toGeneric = ([name = '', description = '']: string[]): DocGeneric => ({ name, description });
+  const inherited = parent ? getTagData(parent, 'generic').map(toGeneric) : [];
+
+  return getTagData(block, 'generic')
+    .map(toGeneric)
+    .filter(generic => !inherited.some(
+      other => other.name === generic.name && other.description === generic.description
+    ));
+}
+
+/**
+ * @doc getVariants getVariants
+ * @description Gets the variants of an enum block, described by its @variant tags
+ * @description When the variants were parsed from the source, all of them are listed (undocumented ones with an empty description) and @variant tags naming no variant are left out
+ * @param block The documentable block
+ * @returns The variants, in declaration order (tag order without source)
+ */
+export function getVariants(block: DocBlock): DocVariant[] {
+  const documented = getTagData(block, 'variant');
+  const sourceVariants = block.item?.variants;
+
+  if (!sourceVariants) {
+    return documented.map(([name = '', description = '']) => ({ name, fields: [], description }));
+  }
+
+  return sourceVariants.map(({ name, fields }) => {
+    const tag = documented.find(([tagName]) => tagName === name);
+    return { name, fields, description: tag?.[1] ?? '' };
+  });
+}
+
+/**
+ * @doc getCategory getCategory
+ * @description Gets the category of a block from its @category tag (the first one if repeated)
+ * @param block The documentable block
+ * @returns The category name or null if the block has no category
+ */
+export function getCategory(block: DocBlock): string | null {
+  return getTagTexts(block, 'category')[0]?.trim() || null;
+}
+
+/**
+ * @doc getGroup getGroup
+ * @description Gets the method group of a block from its @group tag (the first one if repeated)
+ * @param block The documentable block
+ * @returns The group name or null if the block has no group
+ */
+export function getGroup(block: DocBlock): string | null {
+  return getTagTexts(block, 'group')[0]?.trim() || null;
+}
+
+/**
+ * @doc isInternal isInternal
+ * @description Checks whether a block is internal: its own @internal tag decides (@internal false opts out), otherwise members follow their parent type
+ * @param block The documentable block
+ * @param parent The documented type owning the block, if any
+ * @returns True if the block is hidden from rendered output by default
+ */
+export function isInternal(block: DocBlock, parent: DocBlock | null = null): boolean {
+  const tag = getTagData(block, 'internal')[0];
+  if (tag) {
+    return (tag[0] ?? '').trim().toLowerCase() !== 'false';
+  }
+
+  return parent ? isInternal(parent) : false;
+}
+
+/**
+ * @doc getDeprecation getDeprecation
+ * @description Gets the deprecation notice of a block from its first @deprecated tag
+ * @param block The documentable block
+ * @returns The deprecation notice or null if the block is not deprecated
+ */
+export function getDeprecation(block: DocBlock): DocDeprecation | null {
+  const tagData = getTagData(block, 'deprecated');
+  if (tagData.length === 0) {
+    return null;
+  }
+
+  const [since = '', message = ''] = tagData[0];
+  return { since: since || null, message };
+}
+
+/**
+ * @doc getSeeReferences getSeeReferences
+ * @description Gets the keys referenced by the @see tags of a block
+ * @param block The documentable block
+ * @returns The referenced keys, in tag order
+ */
+export function getSeeReferences(block: DocBlock): string[] {
+  return getTagData(block, 'see')
+    .map(fields => fields[0] || '')
+    .filter(id => id.length > 0);
+}
+
+/**
+ * @doc getExampleLanguage getExampleLanguage
+ * @description Gets the language of an example: the info string of its opening code fence, else the language of the tag line, else the source language
+ * @param content The verbatim example content
+ * @param language The language given on the @example tag line (empty if none)
+ * @param block The documentable block (its file extension gives the source language)
+ * @returns The language (e.g. 'c', 'js'), or null if none is known
+ */
+function getExampleLanguage(content: string, language: string, block: DocBlock): string | null {
+  const fence = content.trim().match(/^(?:```|~~~)[ \t]*([\w+-]+)/);
+  return fence?.[1] || language || getLanguage(block.meta.ext);
+}
+
+/**
+ * @doc buildReturn buildReturn
+ * @description Builds the return value from the first @returns tag
+ * @param tagData The @returns tag data
+ * @returns The return value or null if no @returns tag is present
+ */
+function buildReturn(tagData: TagData): DocReturn | null {
+  if (tagData.length === 0) {
+    return null;
+  }
+
+  const [type = '', description = ''] = tagData[0];
+  return { type, description };
+}
+
+/**
+ * @doc getTagTexts getTagTexts
+ * @description Gets the free text of each occurrence of a tag (e.g. @panics conditions)
+ * @param block The documentable block
+ * @param tag The tag name
+ * @returns The non-empty texts, in tag order
+ */
+export function getTagTexts(block: DocBlock, tag: string): string[] {
+  return getTagData(block, tag)
+    .map(fields => fields[0] || '')
+    .filter(text => text.length > 0);
+}
+
+/**
+ * @doc getTagData getTagData
+ * @description Gets the data of a tag from a block, ignoring label and meta
+ * @param block The documentable block
+ * @param tag The tag name
+ * @returns The tag data or an empty array if the tag is absent
+ */
+export function getTagData(block: DocBlock, tag: string): TagData {
+  const data = block[tag];
+  return Array.isArray(data) ? data : [];
+}
+
+/**
+ * @doc serializeDocEntries serializeDocEntries
+ * @description Serializes doc entries to JSON string
+ * @param entries The doc entries to serialize
+ * @returns Formatted JSON string
+ */
+export function serializeDocEntries(entries: DocEntry[]): string {
+  return JSON.stringify(entries, null, 2);
+}
+
+/**
+ * @doc writeDocEntries writeDocEntries
+ * @description Writes the structured entries of a canonical document to a file
+ * @description Creates directory if it doesn't exist
+ * @param doc The canonical document to transform and write
+ * @param outputPath The file path to write to
+ */
+export async function writeDocEntries(
+  doc: CanonicalDoc,
+  outputPath: string
+): Promise<void> {
+  const fs = await import('node:fs/promises');
+  const path = await import('node:path');
+
+  const dir = path.dirname(outputPath);
+  await fs.mkdir(dir, { recursive: true });
+
+  const content = serializeDocEntries(buildDocEntries(doc));
+  await fs.writeFile(outputPath, content, 'utf-8');
+}
@@ -0,0 +1,428 @@
+/**
+ * @doc doc_layout Doc Layout
+ * @description Organizes documentable blocks into pages and module groups for page-based generators
+ */
+
+import type { CanonicalDoc, DocBlock, ItemMethod } from '../types/index';
+import { TYPE_KINDS, findParentBlock, findParentId, getModulePath } from '../resolver/index';
+import { getCategory, getGroup, isInternal } from './ast-generator';
+import { getLabels } from './labels';
+
+/**
+ * @doc LayoutEntry LayoutEntry
+ * @description Documented block with its key and module path
+ */
+export interface LayoutEntry {
+  id: string;
+  block: DocBlock;
+  module: string;
+}
+
+/**
+ * @doc TypePage TypePage
+ * @description Page of a documented type (struct, enum, class...) gathering its methods
+ * @description undocumented lists the trait methods declared in the source without a doc block of their own
+ * @description groups clusters the members by @group (see groupMethods), empty when no member has a group
+ */
+export interface TypePage {
+  entry: LayoutEntry;
+  members: LayoutEntry[];
+  undocumented: ItemMethod[];
+  groups: EntryGroup[];
+  fileName: string;
+}
+
+/**
+ * @doc NotePage NotePage
+ * @description Page of a free-standing doc block (module overview, guide...) not attached to a source item
+ */
+export interface NotePage {
+  entry: LayoutEntry;
+  fileName: string;
+}
+
+/**
+ * @doc ModuleGroup ModuleGroup
+ * @description Entries found in a module, in source order
+ * @description functions lists the free functions of the module (not methods of a type), in function order
+ */
+export interface ModuleGroup {
+  name: string;
+  entries: LayoutEntry[];
+  functions: LayoutEntry[];
+}
+
+/**
+ * @doc FunctionOrder FunctionOrder
+ * @description Order of the free functions of a module: source appearance or alphabetical
+ */
+export type FunctionOrder = 'source' | 'alpha';
+
+/**
+ * @doc FUNCTION_ORDERS FUNCTION_ORDERS
+ * @description Supported function orders (--function-order values)
+ */
+export const FUNCTION_ORDERS: FunctionOrder[] = ['source', 'alpha'];
+
+// Current order of free functions (default: source appearance)
+let FUNCTION_ORDER: FunctionOrder = 'source';
+
+/**
+ * @doc setFunctionOrder setFunctionOrder
+ * @description Sets the order of the free functions in the Functions sections
+ * @param order The function order
+ */
+export function setFunctionOrder(order: FunctionOrder): void {
+  FUNCTION_ORDER = order;
+}
+
+/**
+ * @doc getFunctionOrder getFunctionOrder
+ * @description Gets the order of the free functions in the Functions sections
+ * @returns The function order
+ */
+export function getFunctionOrder(): FunctionOrder {
+  return FUNCTION_ORDER;
+}
+
+// Whether @internal entries are rendered (default: hidden)
+let SHOW_INTERNAL = false;
+
+/**
+ * @doc setShowInternal setShowInternal
+ * @description Sets whether @internal entries are rendered (--show-internal)
+ * @param show True to render internal entries, with an internal badge
+ */
+export function setShowInternal(show: boolean): void {
+  SHOW_INTERNAL = show;
+}
+
+/**
+ * @doc getShowInternal getShowInternal
+ * @description Gets whether @internal entries are rendered
+ * @returns True if internal entries are rendered
+ */
+export function getShowInternal(): boolean {
+  return SHOW_INTERNAL;
+}
+
+/**
+ * @doc EntryGroup EntryGroup
+ * @description Named group of entries shown in the sidebar (module path or @category), or of methods on a type page (@group)
+ * @description uncategorized marks the group of entries without @category or @group
+ */
+export interface EntryGroup {
+  name: string;
+  entries: LayoutEntry[];
+  uncategorized?: boolean;
+}
+
+// Configured order of @category groups (unlisted categories follow alphabetically)
+let CATEGORY_ORDER: string[] = [];
+
+/**
+ * @doc setCategoryOrder setCategoryOrder
+ * @description Sets the order of the @category groups (config key categories)
+ * @param categories The category names, in display order
+ */
+export function setCategoryOrder(categories: string[]): void {
+  CATEGORY_ORDER = categories;
+}
+
+/**
+ * @doc getCategoryOrder getCategoryOrder
+ * @description Gets the configured order of the @category groups
+ * @returns The category names, in display order
+ */
+export function getCategoryOrder(): string[] {
+  return CATEGORY_ORDER;
+}
+
+// Configured order of the @group method groups (unlisted groups follow alphabetically)
+let METHOD_GROUP_ORDER: string[] = [];
+
+/**
+ * @doc setMethodGroupOrder setMethodGroupOrder
+ * @description Sets the order of the @group method groups on type pages (config key methodGroups)
+ * @param groups The group names, in display order
+ */
+export function setMethodGroupOrder(groups: string[]): void {
+  METHOD_GROUP_ORDER = groups;
+}
+
+/**
+ * @doc getMethodGroupOrder getMethodGroupOrder
+ * @description Gets the configured order of the @group method groups
+ * @returns The group names, in display order
+ */
+export function getMethodGroupOrder(): string[] {
+  return METHOD_GROUP_ORDER;
+}
+
+/**
+ * @doc DocLayout DocLayout
+ * @description Pages, standalone entries (rendered on the index) and module groups of a document
+ * @description Free functions are not standalone entries: they are rendered in the Functions section of their module group
+ * @description notes are the free-standing doc blocks (no item follows them), each on its own page
+ * @description groups are the sidebar groups: by @category when any entry has one, by module path otherwise
+ */
+export interface DocLayout {
+  entries: LayoutEntry[];
+  pages: TypePage[];
+  notes: NotePage[];
+  standalone: LayoutEntry[];
+  modules: ModuleGroup[];
+  groups: EntryGroup[];
+  indexFileName: string;
+  files: Map<string, string>;
+}
+
+/**
+ * @doc buildDocLayout buildDocLayout
+ * @description Builds the layout of a document: one page per type and per free-standing note, methods on their type page, the rest on the index
+ * @description Free functions are grouped by module, in source order or alphabetically (see setFunctionOrder)
+ * @description Internal entries are left out unless shown (see setShowInternal): they stay in the document, so references to them render as plain text
+ * @param doc The canonical document containing all blocks
+ * @param extension The extension of generated files (e.g., '.html', '.md')
+ * @returns The document layout
+ */
+export function buildDocLayout(doc: CanonicalDoc, extension: string): DocLayout {
+  const entries: LayoutEntry[] = Object.entries(doc)
+    .filter(([, block]) => SHOW_INTERNAL || !isInternal(block, findParentBlock(doc, block)))
+    .map(([docKey, block]) => ({
+      id: docKey.slice('doc.'.length),
+      block,
+      module: getModulePath(block),
+    }));
+
+  const indexFileName = `index${extension}`;
+  const files = new Map<string, string>();
+  const pages: TypePage[] = [];
+  const pagesById = new Map<string, TypePage>();
+
+  for (const entry of entries) {
+    const item = entry.block.item;
+    if (item && TYPE_KINDS.includes(item.kind)) {
+      let fileName = toFileName(entry.id, extension);
+      if (fileName === indexFileName) {
+        fileName = toFileName(`${entry.id}_type`, extension);
+      }
+
+      const page: TypePage = { entry, members: [], undocumented: [], groups: [], fileName };
+      pages.push(page);
+      pagesById.set(entry.id, page);
+      files.set(entry.id, page.fileName);
+    }
+  }
+
+  const notes: NotePage[] = [];
+  const fileNames = new Set([indexFileName, ...pages.map(page => page.fileName)]);
+  for (const entry of entries) {
+    if (!entry.block.item) {
+      let fileName = toFileName(entry.id, extension);
+      if (fileNames.has(fileName)) {
+        fileName = toFileName(`${entry.id}_note`, extension);
+      }
+
+      fileNames.add(fileName);
+      notes.push({ entry, fileName });
+      files.set(entry.id, fileName);
+    }
+  }
+
+  const standalone: LayoutEntry[] = [];
+  const freeFunctions = new Set<LayoutEntry>();
+  for (const entry of entries) {
+    if (pagesById.has(entry.id) || !entry.block.item) {
+      continue;
+    }
+
+    const parentId = findParentId(doc, entry.block);
+    const page = parentId ? pagesById.get(parentId) : undefined;
+
+    if (page) {
+      page.members.push(entry);
+      files.set(entry.id, page.fileName);
+    } else {
+      if (entry.block.item?.kind === 'function') {
+        freeFunctions.add(entry);
+      } else {
+        standalone.push(entry);
+      }
+      files.set(entry.id, indexFileName);
+    }
+  }
+
+  for (const page of pages) {
+    page.undocumented = (page.entry.block.item?.methods ?? []).filter(
+      method => !page.members.some(member => member.block.item?.name === method.name)
+    );
+    page.groups = groupMethods(page.members);
+  }
+
+  const modulesByName = new Map<string, ModuleGroup>();
+  for (const entry of entries) {
+    let group = modulesByName.get(entry.module);
+    if (!group) {
+      group = { name: entry.module, entries: [], functions: [] };
+      modulesByName.set(entry.module, group);
+    }
+    group.entries.push(entry);
+    if (freeFunctions.has(entry)) {
+      group.functions.push(entry);
+    }
+  }
+
+  if (FUNCTION_ORDER === 'alpha') {
+    for (const group of modulesByName.values()) {
+      group.functions.sort((a, b) => compareNames(a.block.label, b.block.label));
+    }
+  }
+
+  const modules = [...modulesByName.values()];
+
+  return {
+    entries,
+    pages,
+    notes,
+    standalone,
+    modules,
+    groups: groupByCategory(entries) ?? modules,
+    indexFileName,
+    files,
+  };
+}
+
+/**
+ * @doc groupByCategory groupByCategory
+ * @description Groups entries by @category: configured categories first, then the others alphabetically, then the uncategorized entries (localized group name)
+ * @param entries The entries, in source order
+ * @returns The category groups, or null if no entry has a category
+ */
+function groupByCategory(entries: LayoutEntry[]): EntryGroup[] | null {
+  const byCategory = new Map<string, LayoutEntry[]>();
+  const uncategorized: LayoutEntry[] = [];
+
+  for (const entry of entries) {
+    const category = getCategory(entry.block);
+    if (category) {
+      byCategory.set(category, [...(byCategory.get(category) ?? []), entry]);
+    } else {
+      uncategorized.push(entry);
+    }
+  }
+
+  if (byCategory.size === 0) {
+    return null;
+  }
+
+  const listed = CATEGORY_ORDER.filter(name => byCategory.has(name));
+  const others = [...byCategory.keys()]
+    .filter(name => !CATEGORY_ORDER.includes(name))
+    .sort(compareNames);
+
+  const groups: EntryGroup[] = [...listed, ...others].map(name => ({ name, entries: byCategory.get(name)! }));
+  if (uncategorized.length > 0) {
+    groups.push({ name: getLabels().uncategorized, entries: uncategorized, uncategorized: true });
+  }
+
+  return groups;
+}
+
+/**
+ * @doc groupMethods groupMethods
+ * @description Groups the members of a type page by @group: configured groups first, then the others alphabetically, then the ungrouped members (localized group name)
+ * @description Members keep their source order within a group; the ungrouped group is always last, even when empty, so undocumented methods can be listed in it
+ * @param members The members of the type, in source order
+ * @returns The method groups, or an empty list if no member has a group
+ */
+export function groupMethods(members: LayoutEntry[]): EntryGroup[] {
+  const byGroup = new Map<string, LayoutEntry[]>();
+  const ungrouped: LayoutEntry[] = [];
+
+  for (const member of members) {
+    const group = getGroup(member.block);
+    if (group) {
+      byGroup.set(group, [...(byGroup.get(group) ?? []), member]);
+    } else {
+      ungrouped.push(member);
+    }
+  }
+
+  if (byGroup.size === 0) {
+    return [];
+  }
+
+  const listed = METHOD_GROUP_ORDER.filter(name => byGroup.has(name));
+  const others = [...byGroup.keys()]
+    .filter(name => !METHOD_GROUP_ORDER.includes(name))
+    .sort(compareNames);
+
+  return [
+    ...[...listed, ...others].map(name => ({ name, entries: byGroup.get(name)! })),
+    { name: getLabels().otherMethods, entries: ungrouped, uncategorized: true },
+  ];
+}
+
+/**
+ * @doc toSingleFileLayout toSingleFileLayout
+ * @description Turns a layout into a single-document layout: every entry is in the same file, links are in-document anchors
+ * @param layout The document layout
+ * @returns The single-file layout
+ */
+export function toSingleFileLayout(layout: DocLayout): DocLayout {
+  return { ...layout, files: new Map(layout.entries.map(entry => [entry.id, ''])) };
+}
+
+/**
+ * @doc sortModules sortModules
+ * @description Sorts module groups by module path, the crate root first
+ * @param modules The module groups
+ * @returns The sorted groups (entries keep their source order)
+ */
+export function sortModules(modules: ModuleGroup[]): ModuleGroup[] {
+  return [...modules].sort((a, b) => {
+    if (a.name === 'crate' || b.name === 'crate') {
+      return a.name === 'crate' ? (b.name === 'crate' ? 0 : -1) : 1;
+    }
+    return compareNames(a.name, b.name);
+  });
+}
+
+/**
+ * @doc getEntryUrl getEntryUrl
+ * @description Gets the relative URL of an entry (page file and anchor, only the anchor in a single-file layout)
+ * @param layout The document layout
+ * @param id The entry key
+ * @returns The URL, or null if the entry is not part of the layout
+ */
+export function getEntryUrl(layout: DocLayout, id: string): string | null {
+  const file = layout.files.get(id);
+  if (file === undefined) {
+    return null;
+  }
+  return file ? `${file}#${id}` : `#${id}`;
+}
+
+/**
+ * @doc toFileName toFileName
+ * @description Converts an entry key into a safe file name
+ * @param id The entry key
+ * @param extension The file extension
+ * @returns The file name
+ */
+function toFileName(id: string, extension: string): string {
+  return `${id.replace(/[^\w.-]/g, '_')}${extension}`;
+}
+
+/**
+ * @doc compareNames compareNames
+ * @description Compares display names alphabetically with a fixed locale, ties broken by code units
+ * @description The order never depends on the machine, so generated output is byte-identical across runs
+ * @param a The first name
+ * @param b The second name
+ * @returns A negative number if a sorts first, 0 if equal, a positive number otherwise
+ */
+export function compareNames(a: string, b: string): number {
+  return a.localeCompare(b, 'en') || (a < b ? -1 : a > b ? 1 : 0);
+}
@@ -0,0 +1,742 @@
+/**
+ * @doc html_generator HTML Generator
+ * @description Generates a self-contained static HTML site: one page per type, an index and a module sidebar
+ */
+
+import type { CanonicalDoc, DocBlock, DocEntry, DocExample, DocParam, GeneratedFile, ItemMethod } from '../types/index';
+import { buildDocEntry, getDeprecation, getDescription, getSummary } from './ast-generator';
+import {
+  buildDocLayout,
+  getEntryUrl,
+  sortModules,
+  toSingleFileLayout,
+  type DocLayout,
+  type LayoutEntry,
+  type TypePage,
+} from './doc-layout';
+import { SEARCH_SCRIPT, buildSearchIndex } from './search-index';
+import { markGeneratedFile, writeFileIfChanged, writeOutputFiles, type WriteResult } from './output-writer';
+import { getCfgNote, getLabels, getReceiverNote, getOutputLanguage, type OutputLabels } from './labels';
+import { getSourceUrl } from './source-link';
+import { escapeHtml, getHtmlTemplate, renderHtmlTemplate, type TemplateVariables } from './html-template';
+import {
+  findParentBlock,
+  findTraitImplIds,
+  findTypeId,
+  linkifyReferences,
+  renderInlineLinks,
+  resolveReferenceId,
+} from '../resolver/index';
+
+interface HtmlContext {
+  doc: CanonicalDoc;
+  layout: DocLayout;
+  labels: OutputLabels;
+  singleFile?: boolean;
+}
+
+const HTML_STYLE = `
+* { box-sizing: border-box; }
+body { margin: 0; display: flex; font-family: -apple-system, "Segoe UI", Roboto, sans-serif; color: #1f2328; line-height: 1.5; }
+.sidebar { position: sticky; top: 0; height: 100vh; overflow-y: auto; width: 260px; flex-shrink: 0; padding: 1rem; background: #f6f8fa; border-right: 1px solid #d0d7de; }
+.sidebar h2 { font-size: 0.8rem; text-transform: uppercase; color: #656d76; margin: 1rem 0 0.25rem; }
+.sidebar ul { list-style: none; margin: 0; padding: 0; }
+.sidebar a { color: #0969da; text-decoration: none; }
+.sidebar a:hover { text-decoration: underline; }
+.sidebar .home { font-weight: bold; }
+.sidebar .search { display: block; width: 100%; margin-top: 0.75rem; padding: 0.25rem 0.5rem; border: 1px solid #d0d7de; border-radius: 6px; font: inherit; }
+.toc ul { margin: 0.25rem 0; }
+.toc .summary { color: #656d76; }
+main { flex: 1; max-width: 960px; padding: 1rem 2rem; }
+.entry { margin-bottom: 2rem; }
+.kind { font-size: 0.75rem; font-weight: normal; color: #656d76; border: 1px solid #d0d7de; border-radius: 1em; padding: 0 0.5em; vertical-align: middle; }
+.receiver { font-size: 0.75rem; font-weight: normal; font-family: ui-monospace, monospace; color: #656d76; margin-left: 0.25em; vertical-align: middle; }
+.since { font-size: 0.75rem; font-weight: normal; color: #1a7f37; border: 1px solid #1a7f37; border-radius: 1em; padding: 0 0.5em; margin-left: 0.25em; vertical-align: middle; }
+.private { font-size: 0.75rem; font-weight: normal; color: #9a6700; border: 1px dashed #d4a72c; border-radius: 1em; padding: 0 0.5em; margin-left: 0.25em; vertical-align: middle; }
+.internal { font-size: 0.75rem; font-weight: normal; color: #8250df; border: 1px dashed #a475f9; border-radius: 1em; padding: 0 0.5em; margin-left: 0.25em; vertical-align: middle; }
+.aliases { font-size: 0.85rem; color: #656d76; margin: 0.25rem 0; }
+.source { float: right; font-size: 0.8rem; font-weight: normal; }
+.deprecated { background: #fff8c5; border-left: 4px solid #d4a72c; padding: 0.5rem 0.75rem; margin: 0.5rem 0; }
+.deprecated.inherited { font-style: italic; }
+.method-group { border-bottom: 1px solid #d0d7de; padding-bottom: 0.25rem; }
+.empty-example { color: #656d76; font-style: italic; }
+.cfg { background: #ddf4ff; border-left: 4px solid #54aeff; padding: 0.5rem 0.75rem; margin: 0.5rem 0; }
+pre { background: #f6f8fa; padding: 0.75rem; border-radius: 6px; overflow-x: auto; }
+code { font-family: ui-monospace, SFMono-Regular, Menlo, monospace; font-size: 0.9em; }
+table { border-collapse: collapse; }
+th, td { border: 1px solid #d0d7de; padding: 0.25rem 0.75rem; text-align: left; vertical-align: top; }
+a { color: #0969da; }
+`;
+
+/**
+ * @doc generateHtmlSite generateHtmlSite
+ * @description Generates the HTML files of a document (index, one page per type and one per free-standing note)
+ * @description Also emits search-index.json and the search.js script filtering the sidebar
+ * @description Pages and script carry the generation marker
+ * @param doc The canonical document containing all blocks
+ * @returns The generated files, with names relative to the output directory
+ */
+export function generateHtmlSite(doc: CanonicalDoc): GeneratedFile[] {
+  const layout = buildDocLayout(doc, '.html');
+  const context: HtmlContext = { doc, layout, labels: getLabels() };
+
+  const files: GeneratedFile[] = [
+    { fileName: layout.indexFileName, content: renderIndexPage(context) },
+  ];
+
+  for (const page of layout.pages) {
+    files.push({ fileName: page.fileName, content: renderTypePage(page, context) });
+  }
+
+  for (const note of layout.notes) {
+    const content = renderDocument(note.entry.block.label, renderEntry(note.entry, 1, context), context);
+    files.push({ fileName: note.fileName, content });
+  }
+
+  files.push(
+    { fileName: 'search-index.json', content: JSON.stringify(buildSearchIndex(doc, layout), null, 2) },
+    { fileName: 'search.js', content: SEARCH_SCRIPT }
+  );
+
+  return files.map(markGeneratedFile);
+}
+
+/**
+ * @doc writeHtmlSite writeHtmlSite
+ * @description Writes the HTML site of a document to a directory
+ * @description Creates directory if it doesn't exist; unchanged files are not rewritten
+ * @param doc The canonical document containing all blocks
+ * @param outputDir The directory to write to
+ * @param options clean: remove generated files of a previous run that are no longer produced
+ * @returns The written and removed files
+ */
+export async function writeHtmlSite(
+  doc: CanonicalDoc,
+  outputDir: string,
+  options: { clean?: boolean } = {}
+): Promise<WriteResult> {
+  return writeOutputFiles(generateHtmlSite(doc), outputDir, options);
+}
+
+/**
+ * @doc generateHtmlSingleFile generateHtmlSingleFile
+ * @description Generates a single HTML document: a table of contents, then every entry by module path, in source order within a module
+ * @description Anchors are the entry keys, so they stay unique when two items share a name in different modules
+ * @param doc The canonical document containing all blocks
+ * @returns The HTML document, with the generation marker
+ */
+export function generateHtmlSingleFile(doc: CanonicalDoc): string {
+  const layout = toSingleFileLayout(buildDocLayout(doc, '.html'));
+  const context: HtmlContext = { doc, layout, labels: getLabels(), singleFile: true };
+  const modules = sortModules(layout.modules);
+
+  const parts: string[] = [`<h1>${escapeHtml(context.labels.documentation)}</h1>`, '<nav class="toc">', '<ul>'];
+  for (const group of modules) {
+    parts.push(`<li><strong>${escapeHtml(group.name)}</strong>`, '<ul>');
+    for (const entry of group.entries) {
+      const summary = getSummary(getDescription(entry.block));
+      parts.push(
+        `<li>${renderEntryLink(entry.id, entry.block.label, context)}${summary ? `<span class="summary"> — ${renderText(summary, context)}</span>` : ''}</li>`
+      );
+    }
+    parts.push('</ul>', '</li>');
+  }
+  parts.push('</ul>', '</nav>');
+
+  // Methods of a type using @group are rendered grouped under the type instead of in module order
+  const grouped = new Set(layout.pages.filter(page => page.groups.length > 0).flatMap(page => page.members));
+  for (const group of modules) {
+    parts.push(`<h2>${escapeHtml(group.name)}</h2>`);
+    for (const entry of group.entries) {
+      if (grouped.has(entry)) {
+        continue;
+      }
+      parts.push(renderEntry(entry, 3, context));
+      const page = layout.pages.find(typePage => typePage.entry === entry);
+      if (page && page.groups.length > 0) {
+        parts.push(...renderMethods(page, 4, context));
+      } else {
+        for (const method of page?.undocumented ?? []) {
+          parts.push(renderUndocumentedMethod(entry.id, method, 3, context));
+        }
+      }
+    }
+  }
+
+  const content = renderDocument(context.labels.documentation, parts.join('\n'), context);
+  return markGeneratedFile({ fileName: 'doc.html', content }).content;
+}
+
+/**
+ * @doc writeHtmlSingleFile writeHtmlSingleFile
+ * @description Writes the single HTML document of a document to a file (not rewritten if unchanged)
+ * @param doc The canonical document containing all blocks
+ * @param outputPath The file to write to
+ * @returns The written files (empty if the content was unchanged)
+ */
+export async function writeHtmlSingleFile(doc: CanonicalDoc, outputPath: string): Promise<WriteResult> {
+  const written = await writeFileIfChanged(outputPath, generateHtmlSingleFile(doc));
+  return { written: written ? [outputPath] : [], removed: [] };
+}
+
+/**
+ * @doc renderIndexPage renderIndexPage
+ * @description Renders the index page: list of notes and types, free functions by module and other entries that don't belong to a type
+ * @param context The rendering context
+ * @returns The HTML document
+ */
+function renderIndexPage(context: HtmlContext): string {
+  const { labels } = context;
+  const parts: string[] = [`<h1>${escapeHtml(labels.documentation)}</h1>`];
+
+  if (context.layout.notes.length > 0) {
+    parts.push(`<h2>${escapeHtml(labels.notes)}</h2>`, '<ul>');
+    for (const note of context.layout.notes) {
+      parts.push(`<li>${renderEntryLink(note.entry.id, note.entry.block.label, context)}</li>`);
+    }
+    parts.push('</ul>');
+  }
+
+  if (context.layout.pages.length > 0) {
+    parts.push(`<h2>${escapeHtml(labels.types)}</h2>`, '<ul>');
+    for (const page of context.layout.pages) {
+      parts.push(`<li>${renderEntryLink(page.entry.id, page.entry.block.label, context)}</li>`);
+    }
+    parts.push('</ul>');
+  }
+
+  const functionGroups = context.layout.modules.filter(group => group.functions.length > 0);
+  if (functionGroups.length > 0) {
+    parts.push(`<h2>${escapeHtml(labels.functions)}</h2>`);
+    for (const group of functionGroups) {
+      parts.push(`<h3>${escapeHtml(group.name)}</h3>`);
+      for (const entry of group.functions) {
+        parts.push(renderEntry(entry, 4, context));
+      }
+    }
+  }
+
+  if (context.layout.standalone.length > 0) {
+    parts.push(`<h2>${escapeHtml(labels.items)}</h2>`);
+    for (const entry of context.layout.standalone) {
+      parts.push(renderEntry(entry, 3, context));
+    }
+  }
+
+  return renderDocument(labels.documentation, parts.join('\n'), context);
+}
+
+/**
+ * @doc renderTypePage renderTypePage
+ * @description Renders the page of a type with its methods, undocumented trait methods last
+ * @param page The type page
+ * @param context The rendering context
+ * @returns The HTML document
+ */
+function renderTypePage(page: TypePage, context: HtmlContext): string {
+  const parts: string[] = [renderEntry(page.entry, 1, context)];
+
+  if (page.members.length > 0 || page.undocumented.length > 0) {
+    parts.push(`<h2>${escapeHtml(context.labels.methods)}</h2>`);
+    parts.push(...renderMethods(page, 3, context));
+  }
+
+  return renderDocument(page.entry.block.label, parts.join('\n'), context);
+}
+
+/**
+ * @doc renderMethods renderMethods
+ * @description Renders the methods of a type, undocumented trait methods last
+ * @description With @group tags, each group gets a heading at the given level and its methods are one level below; ungrouped and undocumented methods come last, under "Other methods"
+ * @param page The type page
+ * @param level The heading level of the methods (or of the group headings)
+ * @param context The rendering context
+ * @returns The HTML of the methods
+ */
+function renderMethods(page: TypePage, level: number, context: HtmlContext): string[] {
+  const parts: string[] = [];
+
+  if (page.groups.length === 0) {
+    parts.push(...page.members.map(member => renderEntry(member, level, context)));
+    parts.push(...page.undocumented.map(method => renderUndocumentedMethod(page.entry.id, method, level, context)));
+    return parts;
+  }
+
+  for (const group of page.groups) {
+    const undocumented = group.uncategorized ? page.undocumented : [];
+    if (group.entries.length === 0 && undocumented.length === 0) {
+      continue;
+    }
+
+    parts.push(`<h${level} class="method-group">${escapeHtml(group.name)}</h${level}>`);
+    parts.push(...group.entries.map(member => renderEntry(member, level + 1, context)));
+    parts.push(...undocumented.map(method => renderUndocumentedMethod(page.entry.id, method, level + 1, context)));
+  }
+
+  return parts;
+}
+
+/**
+ * @doc renderDocument renderDocument
+ * @description Wraps page content in a full HTML document with inlined CSS and the sidebar (no sidebar nor search script in single-file mode)
+ * @param title The page title
+ * @param body The page content
+ * @param context The rendering context
+ * @returns The HTML document
+ */
+function renderDocument(title: string, body: string, context: HtmlContext): string {
+  return [
+    '<!DOCTYPE html>',
+    `<html lang="${escapeHtml(getOutputLanguage())}">`,
+    '<head>',
+    '<meta charset="utf-8">',
+    '<meta name="viewport" content="width=device-width, initial-scale=1">',
+    `<title>${escapeHtml(title)}</title>`,
+    `<style>${HTML_STYLE}</style>`,
+    '</head>',
+    '<body>',
+    ...(context.singleFile ? [] : [renderSidebar(context)]),
+    '<main>',
+    body,
+    '</main>',
+    ...(context.singleFile ? [] : ['<script src="search.js"></script>']),
+    '</body>',
+    '</html>',
+    '',
+  ].join('\n');
+}
+
+/**
+ * @doc renderSidebar renderSidebar
+ * @description Renders the sidebar listing every entry grouped by module path (or @category), with the search box
+ * @param context The rendering context
+ * @returns The sidebar HTML
+ */
+function renderSidebar(context: HtmlContext): string {
+  const parts: string[] = [
+    '<nav class="sidebar">',
+    `<a class="home" href="${escapeHtml(context.layout.indexFileName)}">${escapeHtml(context.labels.index)}</a>`,
+    `<input class="search" type="search" placeholder="${escapeHtml(context.labels.search)}" aria-label="${escapeHtml(context.labels.search)}">`,
+  ];
+
+  for (const group of context.layout.groups) {
+    parts.push('<section>', `<h2>${escapeHtml(group.name)}</h2>`, '<ul>');
+    for (const entry of group.entries) {
+      const summary = toPlainText(getSummary(getDescription(entry.block)), context);
+      const title = summary ? ` title="${escapeHtml(summary)}"` : '';
+      parts.push(`<li data-id="${escapeHtml(entry.id)}"${title}>${renderEntryLink(entry.id, entry.block.label, context)}</li>`);
+    }
+    parts.push('</ul>', '</section>');
+  }
+
+  parts.push('</nav>');
+  return parts.join('\n');
+}
+
+/**
+ * @doc renderEntry renderEntry
+ * @description Renders a documented entry (heading, signature, description, params, returns, examples)
+ * @param layoutEntry The entry to render
+ * @param level The heading level of the entry title
+ * @param context The rendering context
+ * @returns The entry HTML section
+ */
+function renderEntry(layoutEntry: LayoutEntry, level: number, context: HtmlContext): string {
+  const { id, block } = layoutEntry;
+  const { labels } = context;
+  const entry = buildDocEntry(id, block, findParentBlock(context.doc, block));
+  const subLevel = Math.min(level + 1, 6);
+  const kind = block.item ? ` <span class="kind">${escapeHtml(labels.kinds[block.item.kind])}</span>` : '';
+  const receiver = entry.receiver ? ` <span class="receiver">${escapeHtml(getReceiverNote(entry.receiver, labels))}</span>` : '';
+  const since = entry.since ? ` <span class="since">${escapeHtml(labels.since(entry.since))}</span>` : '';
+  const visibility = entry.private ? ` <span class="private">${escapeHtml(labels.private)}</span>` : '';
+  const internal = entry.internal ? ` <span class="internal">${escapeHtml(labels.internal)}</span>` : '';
+  const sourceUrl = getSourceUrl(block);
+  const source = sourceUrl ? `<a class="source" href="${escapeHtml(sourceUrl)}">[${escapeHtml(labels.source)}]</a>` : '';
+
+  const parts: string[] = [
+    `<section class="entry" id="${escapeHtml(id)}">`,
+    `<h${level}>${escapeHtml(entry.name)}${kind}${receiver}${visibility}${internal}${since}${source}</h${level}>`,
+  ];
+
+  const deprecation = renderDeprecation(block, context);
+  if (deprecation) {
+    parts.push(deprecation);
+  }
+
+  if (entry.cfg) {
+    parts.push(`<div class="cfg">${escapeHtml(getCfgNote(entry.cfg, labels))}</div>`);
+  }
+
+  if (block.item?.signature) {
+    parts.push(`<pre class="signature"><code>${escapeHtml(block.item.signature)}</code></pre>`);
+  }
+
+  if (entry.implements) {
+    const traitId = findTypeId(context.doc, entry.implements);
+    const trait = traitId
+      ? renderEntryLink(traitId, entry.implements, context)
+      : escapeHtml(entry.implements);
+    parts.push(`<p class="implements"><strong>${escapeHtml(labels.implements)}</strong>${escapeHtml(labels.separator)}<code>${trait}</code></p>`);
+  }
+
+  if (entry.aliases.length > 0) {
+    const aliases = entry.aliases.map(alias => `<code>${escapeHtml(alias)}</code>`).join(', ');
+    parts.push(`<p class="aliases">${escapeHtml(labels.aliases)}${escapeHtml(labels.separator)}${aliases}</p>`);
+  }
+
+  if (entry.description) {
+    parts.push(`<p class="description">${renderText(entry.description, context)}</p>`);
+  }
+
+  if (entry.generics.length > 0) {
+    parts.push(
+      `<h${subLevel}>${escapeHtml(labels.typeParameters)}</h${subLevel}>`,
+      '<table>',
+      renderTableHeader([labels.name, labels.description]),
+      '<tbody>'
+    );
+    for (const generic of entry.generics) {
+      parts.push(
+        `<tr><td><code>${escapeHtml(generic.name)}</code></td><td>${renderText(generic.description, context)}</td></tr>`
+      );
+    }
+    parts.push('</tbody>', '</table>');
+  }
+
+  if (entry.variants.length > 0) {
+    parts.push(
+      `<h${subLevel}>${escapeHtml(labels.variants)}</h${subLevel}>`,
+      '<table>',
+      renderTableHeader([labels.name, labels.fields, labels.description]),
+      '<tbody>'
+    );
+    for (const variant of entry.variants) {
+      const fields = variant.fields.map(field => `<code>${renderType(field, context)}</code>`).join(', ');
+      parts.push(
+        `<tr><td><code>${escapeHtml(variant.name)}</code></td><td>${fields}</td><td>${renderText(variant.description, context)}</td></tr>`
+      );
+    }
+    parts.push('</tbody>', '</table>');
+  }
+
+  if (entry.fields.length > 0) {
+    parts.push(renderTypedTable(labels.fields, entry.fields, subLevel, context));
+  }
+
+  if (entry.params.length > 0) {
+    parts.push(renderTypedTable(labels.parameters, entry.params, subLevel, context));
+  }
+
+  if (entry.returns) {
+    const description = entry.returns.description ? ` ${renderText(entry.returns.description, context)}` : '';
+    parts.push(
+      `<h${subLevel}>${escapeHtml(labels.returns)}</h${subLevel}>`,
+      `<p><code>${renderType(entry.returns.type, context)}</code>${description}</p>`
+    );
+  }
+
+  for (const [title, conditions] of [[labels.errors, entry.errors], [labels.panics, entry.panics]] as const) {
+    if (conditions.length > 0) {
+      parts.push(`<h${subLevel}>${escapeHtml(title)}</h${subLevel}>`, '<ul>');
+      for (const condition of conditions) {
+        parts.push(`<li>${escapeHtml(condition)}</li>`);
+      }
+      parts.push('</ul>');
+    }
+  }
+
+  if (entry.examples.length > 0) {
+    const titleLevel = Math.min(subLevel + 1, 6);
+    parts.push(`<h${subLevel}>${escapeHtml(labels.example)}</h${subLevel}>`);
+    for (const example of entry.examples) {
+      if (example.title) {
+        parts.push(`<h${titleLevel} class="example-title">${escapeHtml(example.title)}</h${titleLevel}>`);
+      }
+      parts.push(example.content
+        ? renderExample(example)
+        : `<p class="empty-example">${escapeHtml(labels.emptyExample)}</p>`);
+    }
+  }
+
+  if (block.item?.kind === 'trait') {
+    const implementations = renderImplementations(block.item.name, subLevel, context);
+    if (implementations) {
+      parts.push(implementations);
+    }
+  }
+
+  if (entry.see.length > 0) {
+    // Each reference is a plain link, so cyclic references (A sees B, B sees A) need no special care
+    const links = entry.see.map(target => {
+      const targetId = resolveReferenceId(context.doc, target);
+      return targetId ? renderEntryLink(targetId, context.doc[`doc.${targetId}`].label, context) : escapeHtml(target);
+    });
+    parts.push(`<h${subLevel}>${escapeHtml(labels.seeAlso)}</h${subLevel}>`, `<p>${links.join(', ')}</p>`);
+  }
+
+  parts.push('</section>');
+  const html = parts.join('\n');
+
+  const template = getHtmlTemplate();
+  return template ? renderHtmlTemplate(template, buildTemplateVariables(entry, block, level, html, context)) : html;
+}
+
+/**
+ * @doc buildTemplateVariables buildTemplateVariables
+ * @description Builds the variables of an entry for a --template template
+ * @description Plain fields hold the documentation text (escaped when interpolated); *Html fields and builtin hold generated HTML, meant for the raw filter
+ * @param entry The doc entry
+ * @param block The documentable block of the entry
+ * @param level The heading level of the entry title
+ * @param builtin The built-in rendering of the entry
+ * @param context The rendering context
+ * @returns The template variables
+ */
+function buildTemplateVariables(
+  entry: DocEntry,
+  block: DocBlock,
+  level: number,
+  builtin: string,
+  context: HtmlContext
+): TemplateVariables {
+  const { labels } = context;
+  const typed = (rows: DocParam[]) => rows.map(row => ({
+    name: row.name,
+    type: row.type,
+    typeHtml: renderType(row.type, context),
+    description: row.description,
+    descriptionHtml: renderText(row.description, context),
+    default: row.default ?? null,
+  }));
+
+  return {
+    id: entry.id,
+    name: entry.name,
+    kind: entry.kind === 'note' ? '' : labels.kinds[entry.kind],
+    module: entry.module,
+    level,
+    signature: entry.signature,
+    description: entry.description,
+    descriptionHtml: renderText(entry.description, context),
+    summary: entry.summary,
+    receiver: entry.receiver ? getReceiverNote(entry.receiver, labels) : null,
+    aliases: entry.aliases,
+    since: entry.since,
+    group: entry.group,
+    cfg: entry.cfg ? getCfgNote(entry.cfg, labels) : null,
+    private: entry.private,
+    internal: entry.internal,
+    deprecatedHtml: renderDeprecation(block, context),
+    sourceUrl: getSourceUrl(block),
+    params: typed(entry.params),
+    fields: typed(entry.fields),
+    returns: entry.returns && {
+      type: entry.returns.type,
+      typeHtml: renderType(entry.returns.type, context),
+      description: entry.returns.description,
+      descriptionHtml: renderText(entry.returns.description, context),
+    },
+    examples: entry.examples.map(example => ({
+      title: example.title,
+      content: example.content,
+      language: example.language ?? '',
+      code: getExampleCode(example.content),
+      html: example.content ? renderExample(example) : '',
+    })),
+    labels: Object.fromEntries(Object.entries(labels).filter(([, value]) => typeof value === 'string')),
+    builtin,
+  };
+}
+
+/**
+ * @doc renderImplementations renderImplementations
+ * @description Renders the list of documented implementations of a trait, linked to their impl block
+ * @param traitName The trait name as written in the source
+ * @param level The heading level of the title
+ * @param context The rendering context
+ * @returns The list HTML, or an empty string if no implementation is documented
+ */
+function renderImplementations(traitName: string, level: number, context: HtmlContext): string {
+  const ids = findTraitImplIds(context.doc, traitName);
+  if (ids.length === 0) {
+    return '';
+  }
+
+  const parts: string[] = [`<h${level}>${escapeHtml(context.labels.implementations)}</h${level}>`, '<ul>'];
+  for (const id of ids) {
+    const signature = context.doc[`doc.${id}`].item?.signature ?? id;
+    parts.push(`<li><code>${renderEntryLink(id, signature, context)}</code></li>`);
+  }
+  parts.push('</ul>');
+  return parts.join('\n');
+}
+
+/**
+ * @doc renderUndocumentedMethod renderUndocumentedMethod
+ * @description Renders a trait method without doc block: its name and signature, with an empty description
+ * @param traitId The key of the trait entry (the anchor is <traitId>.<method>)
+ * @param method The declared method
+ * @param level The heading level of the method title
+ * @param context The rendering context
+ * @returns The entry HTML section
+ */
+function renderUndocumentedMethod(traitId: string, method: ItemMethod, level: number, context: HtmlContext): string {
+  return [
+    `<section class="entry" id="${escapeHtml(`${traitId}.${method.name}`)}">`,
+    `<h${level}>${escapeHtml(method.name)} <span class="kind">${escapeHtml(context.labels.kinds.method)}</span></h${level}>`,
+    `<pre class="signature"><code>${escapeHtml(method.signature)}</code></pre>`,
+    '</section>',
+  ].join('\n');
+}
+
+/**
+ * @doc renderTypedTable renderTypedTable
+ * @description Renders a titled name, type, description table (parameters, struct fields)
+ * @description The Default column only appears when a row has a @default
+ * @param title The table title
+ * @param rows The documented parameters or fields
+ * @param level The heading level of the title
+ * @param context The rendering context
+ * @returns The table HTML
+ */
+function renderTypedTable(title: string, rows: DocParam[], level: number, context: HtmlContext): string {
+  const { labels } = context;
+  const hasDefaults = rows.some(row => row.default !== undefined);
+  const columns = [labels.name, labels.type, labels.description, ...(hasDefaults ? [labels.default] : [])];
+  const parts: string[] = [
+    `<h${level}>${escapeHtml(title)}</h${level}>`,
+    '<table>',
+    renderTableHeader(columns),
+    '<tbody>',
+  ];
+
+  for (const row of rows) {
+    const defaultCell = hasDefaults
+      ? `<td>${row.default !== undefined ? `<code>${escapeHtml(row.default)}</code>` : ''}</td>`
+      : '';
+    parts.push(
+      `<tr><td><code>${escapeHtml(row.name)}</code></td><td><code>${renderType(row.type, context)}</code></td><td>${renderText(row.description, context)}</td>${defaultCell}</tr>`
+    );
+  }
+
+  parts.push('</tbody>', '</table>');
+  return parts.join('\n');
+}
+
+/**
+ * @doc renderDeprecation renderDeprecation
+ * @description Renders the deprecation banner of a block, or the hint inherited from a deprecated parent type
+ * @param block The documentable block
+ * @param context The rendering context
+ * @returns The banner HTML or an empty string
+ */
+function renderDeprecation(block: DocBlock, context: HtmlContext): string {
+  const { labels } = context;
+  const deprecation = getDeprecation(block);
+
+  if (deprecation) {
+    const title = deprecation.since ? labels.deprecatedSince(deprecation.since) : labels.deprecated;
+    const message = linkifyReferences(
+      deprecation.message,
+      context.doc,
+      (key) => renderEntryLink(key, key, context),
+      escapeHtml
+    );
+    const separator = message ? `${escapeHtml(labels.separator)}${message}` : '';
+    return `<div class="deprecated"><strong>${escapeHtml(title)}</strong>${separator}</div>`;
+  }
+
+  const parent = findParentBlock(context.doc, block);
+  if (parent && getDeprecation(parent)) {
+    return `<div class="deprecated inherited">${escapeHtml(labels.parentDeprecated(parent.label))}</div>`;
+  }
+
+  return '';
+}
+
+/**
+ * @doc renderType renderType
+ * @description Renders a type, linking identifiers of documented types to their entry
+ * @param type The type as written in the doc (e.g., Option<Calculator>)
+ * @param context The rendering context
+ * @returns The type HTML
+ */
+function renderType(type: string, context: HtmlContext): string {
+  return type.replace(/[A-Za-z_]\w*|[^A-Za-z_]+/g, (token) => {
+    if (/^[A-Za-z_]/.test(token)) {
+      const id = findTypeId(context.doc, token);
+      if (id) {
+        return renderEntryLink(id, token, context);
+      }
+    }
+    return escapeHtml(token);
+  });
+}
+
+/**
+ * @doc renderText renderText
+ * @description Renders documentation text: escaped, with {@link} tokens replaced by links
+ * @param text The documentation text
+ * @param context The rendering context
+ * @returns The text HTML
+ */
+function renderText(text: string, context: HtmlContext): string {
+  return renderInlineLinks(text, context.doc, (id, linkText) => renderEntryLink(id, linkText, context), escapeHtml);
+}
+
+/**
+ * @doc toPlainText toPlainText
+ * @description Turns documentation text into plain text (e.g. for a title attribute): {@link} tokens become their text
+ * @param text The documentation text
+ * @param context The rendering context
+ * @returns The plain text, not escaped
+ */
+function toPlainText(text: string, context: HtmlContext): string {
+  return renderInlineLinks(text, context.doc, (_id, linkText) => linkText);
+}
+
+/**
+ * @doc renderEntryLink renderEntryLink
+ * @description Renders a link to an entry
+ * @param id The entry key
+ * @param text The link text
+ * @param context The rendering context
+ * @returns The link HTML, or the escaped text if the entry has no page
+ */
+function renderEntryLink(id: string, text: string, context: HtmlContext): string {
+  const url = getEntryUrl(context.layout, id);
+  return url ? `<a href="${escapeHtml(url)}">${escapeHtml(text)}</a>` : escapeHtml(text);
+}
+
+/**
+ * @doc renderTableHeader renderTableHeader
+ * @description Renders the header row of a table
+ * @param columns The column titles
+ * @returns The thead HTML
+ */
+function renderTableHeader(columns: string[]): string {
+  return `<thead><tr>${columns.map(column => `<th>${escapeHtml(column)}</th>`).join('')}</tr></thead>`;
+}
+
+/**
+ * @doc renderExample renderExample
+ * @description Renders an example, using the example language as syntax-highlight class (language-<name>)
+ * @param example The example
+ * @returns The example HTML
+ */
+function renderExample(example: DocExample): string {
+  const className = example.language ? ` class="language-${escapeHtml(example.language)}"` : '';
+  return `<pre><code${className}>${escapeHtml(getExampleCode(example.content))}</code></pre>`;
+}
+
+/**
+ * @doc getExampleCode getExampleCode
+ * @description Gets the code of an example, without its code fence
+ * @param content The verbatim example content
+ * @returns The code inside the fence, the whole content when the example is not a fenced block
+ */
+function getExampleCode(content: string): string {
+  const match = content.match(/^```[^\n]*\n([\s\S]*?)\n?```\s*$/);
+  return match ? match[1] : content;
+}
@@ -0,0 +1,292 @@
+/**
+ * @doc html_template HTML Templates
+ * @description Renders HTML entries through a user template (--template) with a small minijinja-style syntax
+ * @description {{ name }} interpolates an escaped value, {{ name | raw }} an unescaped one; {% if %}, {% for %} and {# comments #} are supported
+ */
+
+import { readFileSync } from 'node:fs';
+import { StandardocError } from '../types/index';
+
+/**
+ * @doc TemplateValue TemplateValue
+ * @description Value reachable from a template: text, number, flag, list or record of values
+ */
+export type TemplateValue =
+  | string
+  | number
+  | boolean
+  | null
+  | undefined
+  | TemplateValue[]
+  | { [key: string]: TemplateValue };
+
+/**
+ * @doc TemplateVariables TemplateVariables
+ * @description Variables of a template rendering, by name
+ */
+export type TemplateVariables = Record<string, TemplateValue>;
+
+/**
+ * @doc TemplateNode TemplateNode
+ * @description Node of a parsed template: literal text, an interpolation, an if block or a for block
+ */
+export type TemplateNode =
+  | { type: 'text'; text: string }
+  | { type: 'output'; path: string[]; raw: boolean }
+  | { type: 'if'; path: string[]; negate: boolean; then: TemplateNode[]; otherwise: TemplateNode[] }
+  | { type: 'for'; name: string; path: string[]; body: TemplateNode[] };
+
+type BlockNode = Extract<TemplateNode, { type: 'if' | 'for' }>;
+
+/**
+ * @doc HtmlTemplate HtmlTemplate
+ * @description Parsed template, ready to render
+ */
+export interface HtmlTemplate {
+  nodes: TemplateNode[];
+}
+
+// {{ output }}, {% statement %} and {# comment #} tokens
+const TEMPLATE_TOKEN_REGEX = /\{\{([\s\S]*?)\}\}|\{%([\s\S]*?)%\}|\{#[\s\S]*?#\}/g;
+
+// Variable path: names separated by dots (e.g. returns.type, loop.index)
+const PATH_REGEX = /^[A-Za-z_]\w*(?:\.[A-Za-z_]\w*)*$/;
+
+// Current entry template (null: built-in rendering)
+let HTML_TEMPLATE: HtmlTemplate | null = null;
+
+/**
+ * @doc setHtmlTemplate setHtmlTemplate
+ * @description Sets the template used to render each HTML entry
+ * @param template The parsed template, or null for the built-in rendering
+ */
+export function setHtmlTemplate(template: HtmlTemplate | null): void {
+  HTML_TEMPLATE = template;
+}
+
+/**
+ * @doc getHtmlTemplate getHtmlTemplate
+ * @description Gets the template used to render each HTML entry
+ * @returns The parsed template, or null for the built-in rendering
+ */
+export function getHtmlTemplate(): HtmlTemplate | null {
+  return HTML_TEMPLATE;
+}
+
+/**
+ * @doc loadHtmlTemplate loadHtmlTemplate
+ * @description Reads and parses a template file
+ * @param filePath The template file path
+ * @returns The parsed template
+ * @throws StandardocError if the file can't be read or has a syntax error
+ */
+export function loadHtmlTemplate(filePath: string): HtmlTemplate {
+  let source: string;
+  try {
+    source = readFileSync(filePath, 'utf-8');
+  } catch (error) {
+    throw new StandardocError(
+      `Cannot read template ${filePath}: ${error instanceof Error ? error.message : String(error)}`,
+      filePath
+    );
+  }
+  return parseHtmlTemplate(source, filePath);
+}
+
+/**
+ * @doc parseHtmlTemplate parseHtmlTemplate
+ * @description Parses a template: {{ path }}, {{ path | raw }}, {% if [not] path %}...{% else %}...{% endif %}, {% for name in path %}...{% endfor %} and {# comments #}
+ * @param source The template text
+ * @param filePath The template file, for error locations
+ * @returns The parsed template
+ * @throws StandardocError on an invalid expression, an unknown statement or an unbalanced block
+ */
+export function parseHtmlTemplate(source: string, filePath?: string): HtmlTemplate {
+  const root: TemplateNode[] = [];
+  const stack: Array<{ node: BlockNode; index: number; inElse: boolean }> = [];
+  const fail = (message: string, index: number): StandardocError => {
+    const line = source.slice(0, index).split('\n').length;
+    return new StandardocError(`Invalid template${filePath ? ` ${filePath}` : ''}: ${message} (line ${line})`, filePath, line);
+  };
+  const current = (): TemplateNode[] => {
+    const top = stack[stack.length - 1];
+    if (!top) {
+      return root;
+    }
+    if (top.node.type === 'for') {
+      return top.node.body;
+    }
+    return top.inElse ? top.node.otherwise : top.node.then;
+  };
+
+  let last = 0;
+  for (const match of source.matchAll(TEMPLATE_TOKEN_REGEX)) {
+    const index = match.index ?? 0;
+    if (index > last) {
+      current().push({ type: 'text', text: source.slice(last, index) });
+    }
+    last = index + match[0].length;
+
+    if (match[1] !== undefined) {
+      const [expression, filter, ...extra] = match[1].split('|').map(part => part.trim());
+      if (!PATH_REGEX.test(expression)) {
+        throw fail(`invalid expression '{{${match[1]}}}'`, index);
+      }
+      if ((filter !== undefined && filter !== 'raw') || extra.length > 0) {
+        throw fail(`unknown filter in '{{${match[1]}}}' (only raw is supported)`, index);
+      }
+      current().push({ type: 'output', path: expression.split('.'), raw: filter === 'raw' });
+      continue;
+    }
+
+    if (match[2] === undefined) {
+      continue; // Comment
+    }
+
+    const statement = match[2].trim();
+    const ifMatch = statement.match(/^if\s+(not\s+)?(\S+)$/);
+    const forMatch = statement.match(/^for\s+([A-Za-z_]\w*)\s+in\s+(\S+)$/);
+    const top = stack[stack.length - 1];
+
+    if (ifMatch && PATH_REGEX.test(ifMatch[2])) {
+      const node: BlockNode = { type: 'if', path: ifMatch[2].split('.'), negate: !!ifMatch[1], then: [], otherwise: [] };
+      current().push(node);
+      stack.push({ node, index, inElse: false });
+    } else if (forMatch && PATH_REGEX.test(forMatch[2])) {
+      const node: BlockNode = { type: 'for', name: forMatch[1], path: forMatch[2].split('.'), body: [] };
+      current().push(node);
+      stack.push({ node, index, inElse: false });
+    } else if (statement === 'else') {
+      if (top?.node.type !== 'if' || top.inElse) {
+        throw fail('{% else %} outside of an {% if %} block', index);
+      }
+      top.inElse = true;
+    } else if (statement === 'endif' || statement === 'endfor') {
+      if (!top || `end${top.node.type}` !== statement) {
+        throw fail(`unexpected {% ${statement} %}`, index);
+      }
+      stack.pop();
+    } else {
+      throw fail(`unknown statement '{% ${statement} %}'`, index);
+    }
+  }
+
+  const unclosed = stack[stack.length - 1];
+  if (unclosed) {
+    throw fail(`unclosed {% ${unclosed.node.type} %} block`, unclosed.index);
+  }
+
+  if (last < source.length) {
+    root.push({ type: 'text', text: source.slice(last) });
+  }
+
+  return { nodes: root };
+}
+
+/**
+ * @doc renderHtmlTemplate renderHtmlTemplate
+ * @description Renders a template: interpolated text is HTML-escaped unless it has the raw filter
+ * @description Unknown variables render as empty text; in if blocks, empty text, 0, false, null, empty lists and empty records are false
+ * @description Inside a for block, loop.index (from 1), loop.first and loop.last describe the current item
+ * @param template The parsed template
+ * @param variables The template variables
+ * @returns The rendered HTML
+ */
+export function renderHtmlTemplate(template: HtmlTemplate, variables: TemplateVariables): string {
+  return renderNodes(template.nodes, variables);
+}
+
+/**
+ * @doc renderNodes renderNodes
+ * @description Renders a list of template nodes in a scope
+ * @param nodes The template nodes
+ * @param scope The variables visible to the nodes
+ * @returns The rendered HTML
+ */
+function renderNodes(nodes: TemplateNode[], scope: TemplateVariables): string {
+  let output = '';
+
+  for (const node of nodes) {
+    switch (node.type) {
+      case 'text':
+        output += node.text;
+        break;
+
+      case 'output': {
+        const value = lookupValue(scope, node.path);
+        const text = typeof value === 'string' || typeof value === 'number' || typeof value === 'boolean'
+          ? String(value)
+          : '';
+        output += node.raw ? text : escapeHtml(text);
+        break;
+      }
+
+      case 'if': {
+        const truthy = isTruthy(lookupValue(scope, node.path)) !== node.negate;
+        output += renderNodes(truthy ? node.then : node.otherwise, scope);
+        break;
+      }
+
+      case 'for': {
+        const value = lookupValue(scope, node.path);
+        const items = Array.isArray(value) ? value : [];
+        items.forEach((item, index) => {
+          const loop = { index: index + 1, first: index === 0, last: index === items.length - 1 };
+          output += renderNodes(node.body, { ...scope, [node.name]: item, loop });
+        });
+        break;
+      }
+    }
+  }
+
+  return output;
+}
+
+/**
+ * @doc lookupValue lookupValue
+ * @description Resolves a variable path in a scope
+ * @param scope The visible variables
+ * @param path The path segments (e.g. ['returns', 'type'])
+ * @returns The value, or undefined if a segment is missing
+ */
+function lookupValue(scope: TemplateVariables, path: string[]): TemplateValue {
+  let value: TemplateValue = scope;
+  for (const segment of path) {
+    if (value === null || typeof value !== 'object' || Array.isArray(value) || !Object.hasOwn(value, segment)) {
+      return undefined;
+    }
+    value = value[segment];
+  }
+  return value;
+}
+
+/**
+ * @doc isTruthy isTruthy
+ * @description Tests a value in an if block: empty text, 0, false, null, empty lists and empty records are false
+ * @param value The value
+ * @returns True if the value is truthy
+ */
+function isTruthy(value: TemplateValue): boolean {
+  if (Array.isArray(value)) {
+    return value.length > 0;
+  }
+  if (value !== null && typeof value === 'object') {
+    return Object.keys(value).length > 0;
+  }
+  return !!value;
+}
+
+/**
+ * @doc escapeHtml escapeHtml
+ * @description Escapes HTML special characters
+ * @param text The text to escape
+ * @returns The escaped text
+ */
+export function escapeHtml(text: string): string {
+  return text
+    .replace(/&/g, '&amp;')
+    .replace(/</g, '&lt;')
+    .replace(/>/g, '&gt;')
+    .replace(/"/g, '&quot;')
+    .replace(/'/g, '&#39;');
+}
@@ -1,6 +1,15 @@
 /**
  * @doc generator Generator Module
- * @description Canonical JSON generator for Standardoc
+ * @description Canonical JSON, structured AST, HTML and Markdown generators for Standardoc
  */
 
 export * from './json-generator';
+export * from './output-writer';
+export * from './labels';
+export * from './source-link';
+export * from './ast-generator';
+export * from './doc-layout';
+export * from './search-index';
+export * from './html-template';
+export * from './html-generator';
+export * from './markdown-generator';
@@ -4,35 +4,105 @@
  */
 
 import type { DocBlock, CanonicalDoc } from '../types/index';
+import { StandardocError } from '../types/index';
+import { getAliases, getModulePath } from '../resolver/index';
 
 /**
  * @doc generateCanonicalDoc generateCanonicalDoc
  * @description Generates canonical JSON from a list of DocBlocks
  * @description Creates doc.<key> structure from blocks with their metadata keys
+ * @description Explicit keys are registered first; generated keys (bare @doc) are derived from the item path and get a numeric suffix (_2, _3...) in block order on collision
  * @param blocks Array of documentable blocks to transform
  * @returns Canonical document object with doc.<key> structure
- * @throws Error if duplicate keys are found
+ * @throws StandardocError if two blocks use the same explicit key, or an @alias collides with a key or another alias
  */
 export function generateCanonicalDoc(blocks: DocBlock[]): CanonicalDoc {
   const canonical: CanonicalDoc = {};
+  const generated: DocBlock[] = [];
 
   for (const block of blocks) {
-    const meta = block.meta as typeof block.meta & { key?: string };
-    const key = meta.key || block.label;
+    if (block.meta.generatedKey) {
+      generated.push(block);
+      continue;
+    }
+
+    const key = block.meta.key || block.label;
     const docKey = `doc.${key}` as const;
 
-    if (canonical[docKey]) {
-      throw new Error(
-        `Duplicate key: ${docKey}. The label "${key}" is already used.`
+    const existing = canonical[docKey];
+    if (existing) {
+      throw new StandardocError(
+        `Duplicate key: ${docKey}. The key "${key}" is already used at ${existing.meta.path}:${existing.meta.line}`,
+        block.meta.path,
+        block.meta.line
       );
     }
 
     canonical[docKey] = block;
   }
 
+  // Keys of generated blocks never take over an explicit key
+  for (const block of generated) {
+    const base = generateKey(block);
+    let key = base;
+    for (let suffix = 2; canonical[`doc.${key}`]; suffix++) {
+      key = `${base}_${suffix}`;
+    }
+
+    block.meta.key = key;
+    canonical[`doc.${key}`] = block;
+  }
+
+  validateAliases(canonical);
   return canonical;
 }
 
+/**
+ * @doc validateAliases validateAliases
+ * @description Checks that each @alias names a single entry: an alias that is the key of another entry, or an alias of another entry, would make references ambiguous
+ * @param canonical The canonical document, keys assigned
+ * @throws StandardocError on the first colliding alias
+ */
+function validateAliases(canonical: CanonicalDoc): void {
+  const aliases = new Map<string, DocBlock>();
+
+  for (const block of Object.values(canonical)) {
+    for (const alias of getAliases(block)) {
+      const existing = canonical[`doc.${alias}`] ?? aliases.get(alias);
+      if (existing && existing !== block) {
+        const usage = canonical[`doc.${alias}`] ? 'key' : 'alias';
+        throw new StandardocError(
+          `Alias collision: "${alias}" of doc.${block.meta.key} is already the ${usage} of doc.${existing.meta.key} at ${existing.meta.path}:${existing.meta.line}`,
+          block.meta.path,
+          block.meta.line
+        );
+      }
+      aliases.set(alias, block);
+    }
+  }
+}
+
+/**
+ * @doc generateKey generateKey
+ * @description Derives a key from the module path, parent type and name of a block item
+ * @description Example: fn length in impl Vector of src/math/vector.rs becomes math_vector_vector_length
+ * @param block The documentable block (with a detected item)
+ * @returns The generated key (lowercase, word characters only)
+ */
+function generateKey(block: DocBlock): string {
+  const modulePath = getModulePath(block);
+  const segments = modulePath === 'crate' ? [] : modulePath.split('::');
+  if (block.item?.parent) {
+    segments.push(block.item.parent);
+  }
+  segments.push(block.item?.name ?? block.label);
+
+  return segments
+    .map(segment => segment.toLowerCase().replace(/\W+/g, '_').replace(/^_+|_+$/g, ''))
+    .filter(Boolean)
+    .join('_');
+}
+
 /**
  * @doc serializeCanonicalDoc serializeCanonicalDoc
  * @description Serializes canonical document to JSON string
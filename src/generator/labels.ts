@@ -0,0 +1,229 @@
+/**
+ * @doc labels Output Labels
+ * @description Static strings of the rendered output (section titles, table headers...), by output language
+ * @description Only the generator's own strings are localized, never the documentation text
+ */
+
+import type { ItemKind, MethodReceiver } from '../types/index';
+
+/**
+ * @doc OutputLabels OutputLabels
+ * @description Label set of an output language
+ */
+export interface OutputLabels {
+  documentation: string;
+  index: string;
+  search: string;
+  types: string;
+  notes: string;
+  items: string;
+  functions: string;
+  uncategorized: string;
+  methods: string;
+  otherMethods: string;
+  typeParameters: string;
+  variants: string;
+  parameters: string;
+  returns: string;
+  errors: string;
+  panics: string;
+  example: string;
+  emptyExample: string;
+  seeAlso: string;
+  name: string;
+  type: string;
+  fields: string;
+  description: string;
+  default: string;
+  separator: string;
+  source: string;
+  deprecated: string;
+  private: string;
+  internal: string;
+  implementations: string;
+  implements: string;
+  staticMethod: string;
+  aliases: string;
+  kinds: Record<ItemKind, string>;
+  since: (version: string) => string;
+  deprecatedSince: (version: string) => string;
+  parentDeprecated: (parent: string) => string;
+  requiresFeature: (feature: string) => string;
+  requiresCfg: (condition: string) => string;
+}
+
+/**
+ * @doc OUTPUT_LABELS OUTPUT_LABELS
+ * @description Label sets keyed by output language (add a key to support a new locale)
+ */
+export const OUTPUT_LABELS: Record<string, OutputLabels> = {
+  en: {
+    documentation: 'Documentation',
+    index: 'Index',
+    search: 'Search...',
+    types: 'Types',
+    notes: 'Notes',
+    items: 'Items',
+    functions: 'Functions',
+    uncategorized: 'Uncategorized',
+    methods: 'Methods',
+    otherMethods: 'Other methods',
+    typeParameters: 'Type Parameters',
+    variants: 'Variants',
+    parameters: 'Parameters',
+    returns: 'Returns',
+    errors: 'Errors',
+    panics: 'Panics',
+    example: 'Example',
+    emptyExample: 'No code for this example.',
+    seeAlso: 'See also',
+    name: 'Name',
+    type: 'Type',
+    fields: 'Fields',
+    description: 'Description',
+    default: 'Default',
+    separator: ': ',
+    source: 'source',
+    deprecated: 'Deprecated',
+    private: 'private',
+    internal: 'internal',
+    implementations: 'Implementations',
+    implements: 'Implements',
+    staticMethod: 'static',
+    aliases: 'Also known as',
+    kinds: {
+      function: 'Function',
+      method: 'Method',
+      struct: 'Struct',
+      enum: 'Enum',
+      trait: 'Trait',
+      impl: 'Impl',
+      class: 'Class',
+      interface: 'Interface',
+      module: 'Module',
+      type: 'Type',
+      const: 'Constant',
+    },
+    since: version => `Since ${version}`,
+    deprecatedSince: version => `Deprecated since ${version}`,
+    parentDeprecated: parent => `Deprecated: ${parent} is deprecated`,
+    requiresFeature: feature => `Available on crate feature ${feature} only`,
+    requiresCfg: condition => `Available on cfg(${condition}) only`,
+  },
+  fr: {
+    documentation: 'Documentation',
+    index: 'Index',
+    search: 'Rechercher...',
+    types: 'Types',
+    notes: 'Notes',
+    items: 'Éléments',
+    functions: 'Fonctions',
+    uncategorized: 'Non classé',
+    methods: 'Méthodes',
+    otherMethods: 'Autres méthodes',
+    typeParameters: 'Paramètres de type',
+    variants: 'Variantes',
+    parameters: 'Paramètres',
+    returns: 'Retourne',
+    errors: 'Erreurs',
+    panics: 'Paniques',
+    example: 'Exemple',
+    emptyExample: 'Aucun code pour cet exemple.',
+    seeAlso: 'Voir aussi',
+    name: 'Nom',
+    type: 'Type',
+    fields: 'Champs',
+    description: 'Description',
+    default: 'Défaut',
+    separator: ' : ',
+    source: 'source',
+    deprecated: 'Obsolète',
+    private: 'privé',
+    internal: 'interne',
+    implementations: 'Implémentations',
+    implements: 'Implémente',
+    staticMethod: 'statique',
+    aliases: 'Aussi appelé',
+    kinds: {
+      function: 'Fonction',
+      method: 'Méthode',
+      struct: 'Structure',
+      enum: 'Énumération',
+      trait: 'Trait',
+      impl: 'Impl',
+      class: 'Classe',
+      interface: 'Interface',
+      module: 'Module',
+      type: 'Type',
+      const: 'Constante',
+    },
+    since: version => `Depuis ${version}`,
+    deprecatedSince: version => `Obsolète depuis ${version}`,
+    parentDeprecated: parent => `Obsolète : ${parent} est obsolète`,
+    requiresFeature: feature => `Disponible uniquement avec la feature ${feature}`,
+    requiresCfg: condition => `Disponible uniquement avec cfg(${condition})`,
+  },
+};
+
+/**
+ * @doc OUTPUT_LANGUAGES OUTPUT_LANGUAGES
+ * @description Supported output languages (--lang values)
+ */
+export const OUTPUT_LANGUAGES = Object.keys(OUTPUT_LABELS);
+
+// Current output language (default: English)
+let OUTPUT_LANGUAGE = 'en';
+
+/**
+ * @doc setOutputLanguage setOutputLanguage
+ * @description Sets the language of the rendered labels
+ * @param language The output language (e.g., 'en', 'fr')
+ * @throws Error if the language has no label set
+ */
+export function setOutputLanguage(language: string): void {
+  if (!OUTPUT_LABELS[language]) {
+    throw new Error(`Unknown output language: ${language} (expected: ${OUTPUT_LANGUAGES.join(', ')})`);
+  }
+  OUTPUT_LANGUAGE = language;
+}
+
+/**
+ * @doc getOutputLanguage getOutputLanguage
+ * @description Gets the language of the rendered labels
+ * @returns The output language
+ */
+export function getOutputLanguage(): string {
+  return OUTPUT_LANGUAGE;
+}
+
+/**
+ * @doc getLabels getLabels
+ * @description Gets the label set of the current output language
+ * @returns The labels
+ */
+export function getLabels(): OutputLabels {
+  return OUTPUT_LABELS[OUTPUT_LANGUAGE];
+}
+
+/**
+ * @doc getCfgNote getCfgNote
+ * @description Gets the availability note of a conditionally compiled item (#[cfg(...)])
+ * @param condition The cfg condition
+ * @param labels The labels of the output language
+ * @returns The note: the feature name for feature = "x", the whole condition otherwise
+ */
+export function getCfgNote(condition: string, labels: OutputLabels): string {
+  const feature = condition.match(/^feature\s*=\s*"([^"]*)"$/);
+  return feature ? labels.requiresFeature(feature[1]) : labels.requiresCfg(condition);
+}
+
+/**
+ * @doc getReceiverNote getReceiverNote
+ * @description Gets the receiver indicator of a Rust method, shown next to its kind
+ * @param receiver The method receiver
+ * @param labels The labels of the output language
+ * @returns The indicator: the receiver in parentheses (e.g. "(&mut self)"), the static label for associated functions
+ */
+export function getReceiverNote(receiver: MethodReceiver, labels: OutputLabels): string {
+  return receiver === 'static' ? labels.staticMethod : `(${receiver})`;
+}
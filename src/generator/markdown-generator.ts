@@ -0,0 +1,719 @@
+/**
+ * @doc markdown_generator Markdown Generator
+ * @description Generates Markdown files for an mdBook: one file per type, an index and a SUMMARY.md
+ */
+
+import type { CanonicalDoc, DocBlock, DocEntry, DocExample, DocParam, GeneratedFile, ItemMethod } from '../types/index';
+import { buildDocEntry, getDeprecation, getDescription, getSummary } from './ast-generator';
+import {
+  buildDocLayout,
+  sortModules,
+  toSingleFileLayout,
+  type DocLayout,
+  type LayoutEntry,
+  type TypePage,
+} from './doc-layout';
+import { markGeneratedFile, writeFileIfChanged, writeOutputFiles, type WriteResult } from './output-writer';
+import { getCfgNote, getLabels, getReceiverNote, type OutputLabels } from './labels';
+import { getSourceUrl } from './source-link';
+import {
+  findParentBlock,
+  findTraitImplIds,
+  findTypeId,
+  linkifyReferences,
+  renderInlineLinks,
+  resolveReferenceId,
+} from '../resolver/index';
+import { getLanguage } from '../parser/index';
+
+interface MarkdownContext {
+  doc: CanonicalDoc;
+  layout: DocLayout;
+  anchors: Map<string, string>;
+  labels: OutputLabels;
+}
+
+interface MarkdownPage {
+  fileName: string;
+  lines: string[];
+  anchors: Map<string, string>;
+  slug: (text: string) => string;
+  idAnchors?: boolean;
+}
+
+/**
+ * @doc generateMarkdownBook generateMarkdownBook
+ * @description Generates the Markdown files of a document (index, one file per type and SUMMARY.md)
+ * @description Pages are rendered twice: the first pass collects the GitHub anchors of every heading so links can target them
+ * @description Every file starts with the generation marker comment
+ * @param doc The canonical document containing all blocks
+ * @returns The generated files, with names relative to the output directory
+ */
+export function generateMarkdownBook(doc: CanonicalDoc): GeneratedFile[] {
+  const layout = buildDocLayout(doc, '.md');
+  const context: MarkdownContext = { doc, layout, anchors: new Map(), labels: getLabels() };
+
+  renderPages(context);
+  const files = renderPages(context);
+  files.push({ fileName: 'SUMMARY.md', content: renderSummary(context) });
+
+  return files.map(markGeneratedFile);
+}
+
+/**
+ * @doc writeMarkdownBook writeMarkdownBook
+ * @description Writes the Markdown files of a document to a directory
+ * @description Creates directory if it doesn't exist; unchanged files are not rewritten
+ * @param doc The canonical document containing all blocks
+ * @param outputDir The directory to write to
+ * @param options clean: remove generated files of a previous run that are no longer produced
+ * @returns The written and removed files
+ */
+export async function writeMarkdownBook(
+  doc: CanonicalDoc,
+  outputDir: string,
+  options: { clean?: boolean } = {}
+): Promise<WriteResult> {
+  return writeOutputFiles(generateMarkdownBook(doc), outputDir, options);
+}
+
+/**
+ * @doc generateMarkdownSingleFile generateMarkdownSingleFile
+ * @description Generates a single Markdown document: a table of contents, then every entry by module path, in source order within a module
+ * @description Entry headings get an explicit anchor named after the entry key, so they stay unique when two items share a name in different modules
+ * @param doc The canonical document containing all blocks
+ * @returns The Markdown document, with the generation marker
+ */
+export function generateMarkdownSingleFile(doc: CanonicalDoc): string {
+  const layout = toSingleFileLayout(buildDocLayout(doc, '.md'));
+  const context: MarkdownContext = { doc, layout, anchors: new Map(), labels: getLabels() };
+  const modules = sortModules(layout.modules);
+  const page: MarkdownPage = { ...createPage('', context.anchors), idAnchors: true };
+
+  pushHeading(page, 1, context.labels.documentation);
+  for (const group of modules) {
+    page.lines.push(`- ${codeSpan(group.name)}`);
+    for (const entry of group.entries) {
+      const summary = getSummary(getDescription(entry.block));
+      page.lines.push(
+        `  - ${renderEntryLink(entry.id, entry.block.label, page, context)}${summary ? ` — ${renderText(summary, page, context)}` : ''}`
+      );
+    }
+  }
+  page.lines.push('');
+
+  // Methods of a type using @group are rendered grouped under the type instead of in module order
+  const grouped = new Set(layout.pages.filter(typePage => typePage.groups.length > 0).flatMap(typePage => typePage.members));
+  for (const group of modules) {
+    pushHeading(page, 2, group.name);
+    for (const entry of group.entries) {
+      if (grouped.has(entry)) {
+        continue;
+      }
+      renderEntry(page, entry, 3, context);
+      const typePage = layout.pages.find(candidate => candidate.entry === entry);
+      if (typePage && typePage.groups.length > 0) {
+        renderMethods(page, typePage, 4, context);
+      } else {
+        for (const method of typePage?.undocumented ?? []) {
+          renderUndocumentedMethod(page, method, 3, context);
+        }
+      }
+    }
+  }
+
+  return markGeneratedFile({ fileName: 'doc.md', content: joinLines(page.lines) }).content;
+}
+
+/**
+ * @doc writeMarkdownSingleFile writeMarkdownSingleFile
+ * @description Writes the single Markdown document of a document to a file (not rewritten if unchanged)
+ * @param doc The canonical document containing all blocks
+ * @param outputPath The file to write to
+ * @returns The written files (empty if the content was unchanged)
+ */
+export async function writeMarkdownSingleFile(doc: CanonicalDoc, outputPath: string): Promise<WriteResult> {
+  const written = await writeFileIfChanged(outputPath, generateMarkdownSingleFile(doc));
+  return { written: written ? [outputPath] : [], removed: [] };
+}
+
+/**
+ * @doc renderPages renderPages
+ * @description Renders the index, type and note pages, recording the anchor of each entry heading
+ * @param context The rendering context
+ * @returns The rendered pages
+ */
+function renderPages(context: MarkdownContext): GeneratedFile[] {
+  const files: GeneratedFile[] = [];
+
+  const index = createPage(context.layout.indexFileName, context.anchors);
+  renderIndexPage(index, context);
+  files.push({ fileName: index.fileName, content: joinLines(index.lines) });
+
+  for (const typePage of context.layout.pages) {
+    const page = createPage(typePage.fileName, context.anchors);
+    renderTypePage(page, typePage, context);
+    files.push({ fileName: page.fileName, content: joinLines(page.lines) });
+  }
+
+  for (const note of context.layout.notes) {
+    const page = createPage(note.fileName, context.anchors);
+    renderEntry(page, note.entry, 1, context);
+    files.push({ fileName: page.fileName, content: joinLines(page.lines) });
+  }
+
+  return files;
+}
+
+/**
+ * @doc renderIndexPage renderIndexPage
+ * @description Renders the index page: list of notes and types, free functions by module and other entries that don't belong to a type
+ * @param page The page being rendered
+ * @param context The rendering context
+ */
+function renderIndexPage(page: MarkdownPage, context: MarkdownContext): void {
+  pushHeading(page, 1, context.labels.documentation);
+
+  if (context.layout.notes.length > 0) {
+    pushHeading(page, 2, context.labels.notes);
+    for (const note of context.layout.notes) {
+      page.lines.push(`- ${renderEntryLink(note.entry.id, note.entry.block.label, page, context)}`);
+    }
+    page.lines.push('');
+  }
+
+  if (context.layout.pages.length > 0) {
+    pushHeading(page, 2, context.labels.types);
+    for (const typePage of context.layout.pages) {
+      page.lines.push(`- ${renderEntryLink(typePage.entry.id, typePage.entry.block.label, page, context)}`);
+    }
+    page.lines.push('');
+  }
+
+  const functionGroups = context.layout.modules.filter(group => group.functions.length > 0);
+  if (functionGroups.length > 0) {
+    pushHeading(page, 2, context.labels.functions);
+    for (const group of functionGroups) {
+      pushHeading(page, 3, group.name);
+      for (const entry of group.functions) {
+        renderEntry(page, entry, 4, context);
+      }
+    }
+  }
+
+  if (context.layout.standalone.length > 0) {
+    pushHeading(page, 2, context.labels.items);
+    for (const entry of context.layout.standalone) {
+      renderEntry(page, entry, 3, context);
+    }
+  }
+}
+
+/**
+ * @doc renderTypePage renderTypePage
+ * @description Renders the page of a type with its methods, undocumented trait methods last
+ * @param page The page being rendered
+ * @param typePage The type page layout
+ * @param context The rendering context
+ */
+function renderTypePage(page: MarkdownPage, typePage: TypePage, context: MarkdownContext): void {
+  renderEntry(page, typePage.entry, 1, context);
+
+  if (typePage.members.length > 0 || typePage.undocumented.length > 0) {
+    pushHeading(page, 2, context.labels.methods);
+    renderMethods(page, typePage, 3, context);
+  }
+}
+
+/**
+ * @doc renderMethods renderMethods
+ * @description Renders the methods of a type, undocumented trait methods last
+ * @description With @group tags, each group gets a heading at the given level and its methods are one level below; ungrouped and undocumented methods come last, under "Other methods"
+ * @param page The page being rendered
+ * @param typePage The type page layout
+ * @param level The heading level of the methods (or of the group headings)
+ * @param context The rendering context
+ */
+function renderMethods(page: MarkdownPage, typePage: TypePage, level: number, context: MarkdownContext): void {
+  if (typePage.groups.length === 0) {
+    for (const member of typePage.members) {
+      renderEntry(page, member, level, context);
+    }
+    for (const method of typePage.undocumented) {
+      renderUndocumentedMethod(page, method, level, context);
+    }
+    return;
+  }
+
+  for (const group of typePage.groups) {
+    const undocumented = group.uncategorized ? typePage.undocumented : [];
+    if (group.entries.length === 0 && undocumented.length === 0) {
+      continue;
+    }
+
+    pushHeading(page, level, group.name);
+    for (const member of group.entries) {
+      renderEntry(page, member, level + 1, context);
+    }
+    for (const method of undocumented) {
+      renderUndocumentedMethod(page, method, level + 1, context);
+    }
+  }
+}
+
+/**
+ * @doc renderUndocumentedMethod renderUndocumentedMethod
+ * @description Renders a trait method without doc block: its name and signature, with an empty description
+ * @param page The page being rendered
+ * @param method The declared method
+ * @param level The heading level of the method title
+ * @param context The rendering context
+ */
+function renderUndocumentedMethod(page: MarkdownPage, method: ItemMethod, level: number, context: MarkdownContext): void {
+  pushHeading(page, level, method.name);
+  page.lines.push(`*${context.labels.kinds.method}*`, '', '```rust', method.signature, '```', '');
+}
+
+/**
+ * @doc renderSummary renderSummary
+ * @description Renders the mdBook SUMMARY.md listing the index, the note pages and the type pages (grouped by @category when used)
+ * @param context The rendering context
+ * @returns The SUMMARY.md content
+ */
+function renderSummary(context: MarkdownContext): string {
+  const { layout } = context;
+  const lines = ['# Summary', '', `- [${escapeLinkText(context.labels.documentation)}](${layout.indexFileName})`];
+  lines.push(...layout.notes.map(note => `- [${escapeLinkText(note.entry.block.label)}](${note.fileName})`));
+
+  // With @category groups, type pages are listed under one mdBook part title per category
+  if (layout.groups !== layout.modules) {
+    for (const group of layout.groups) {
+      const pages = layout.pages.filter(page => group.entries.includes(page.entry));
+      if (pages.length > 0) {
+        lines.push('', `# ${group.name}`, '');
+        lines.push(...pages.map(page => `- [${escapeLinkText(page.entry.block.label)}](${page.fileName})`));
+      }
+    }
+    return joinLines(lines);
+  }
+
+  for (const page of layout.pages) {
+    lines.push(`  - [${escapeLinkText(page.entry.block.label)}](${page.fileName})`);
+  }
+
+  return joinLines(lines);
+}
+
+/**
+ * @doc renderEntry renderEntry
+ * @description Renders a documented entry (heading, signature, description, type parameters, params, returns, examples)
+ * @description Prose sections are left intact, table cells are escaped
+ * @param page The page being rendered
+ * @param layoutEntry The entry to render
+ * @param level The heading level of the entry title
+ * @param context The rendering context
+ */
+function renderEntry(page: MarkdownPage, layoutEntry: LayoutEntry, level: number, context: MarkdownContext): void {
+  const { id, block } = layoutEntry;
+  const { labels } = context;
+  const entry = buildDocEntry(id, block, findParentBlock(context.doc, block));
+  const subLevel = Math.min(level + 1, 6);
+
+  pushHeading(page, level, entry.name, id);
+
+  const sourceUrl = getSourceUrl(block);
+  const badges = [
+    block.item ? `*${labels.kinds[block.item.kind]}*` : '',
+    entry.receiver ? `*${getReceiverNote(entry.receiver, labels)}*` : '',
+    entry.private ? `*${labels.private}*` : '',
+    entry.internal ? `*${labels.internal}*` : '',
+    entry.since ? `*${labels.since(entry.since)}*` : '',
+    sourceUrl ? `[\\[${labels.source}\\]](<${sourceUrl}>)` : '',
+  ].filter(Boolean);
+  if (badges.length > 0) {
+    page.lines.push(badges.join(' · '), '');
+  }
+
+  const deprecation = renderDeprecation(block, page, context);
+  if (deprecation) {
+    page.lines.push(deprecation, '');
+  }
+
+  if (entry.cfg) {
+    page.lines.push(`> ${getCfgNote(entry.cfg, labels)}`, '');
+  }
+
+  if (block.item?.signature) {
+    page.lines.push(`\`\`\`${getFenceLanguage(block)}`, block.item.signature, '```', '');
+  }
+
+  if (entry.implements) {
+    const traitId = findTypeId(context.doc, entry.implements);
+    const trait = traitId ? renderEntryLink(traitId, entry.implements, page, context) : codeSpan(entry.implements);
+    page.lines.push(`**${labels.implements}**${labels.separator}${trait}`, '');
+  }
+
+  if (entry.aliases.length > 0) {
+    page.lines.push(`*${labels.aliases}${labels.separator}${entry.aliases.map(codeSpan).join(', ')}*`, '');
+  }
+
+  if (entry.description) {
+    page.lines.push(renderText(entry.description, page, context), '');
+  }
+
+  renderTables(page, entry, subLevel, context);
+
+  if (entry.returns) {
+    const description = entry.returns.description ? ` ${renderText(entry.returns.description, page, context)}` : '';
+    pushHeading(page, subLevel, labels.returns);
+    page.lines.push(`${renderType(entry.returns.type, page, context)}${description}`, '');
+  }
+
+  for (const [title, conditions] of [[labels.errors, entry.errors], [labels.panics, entry.panics]] as const) {
+    if (conditions.length > 0) {
+      pushHeading(page, subLevel, title);
+      page.lines.push(...conditions.map(condition => `- ${condition}`), '');
+    }
+  }
+
+  if (entry.examples.length > 0) {
+    pushHeading(page, subLevel, labels.example);
+    for (const example of entry.examples) {
+      if (example.title) {
+        pushHeading(page, Math.min(subLevel + 1, 6), example.title);
+      }
+      page.lines.push(example.content ? renderExample(example) : `*${labels.emptyExample}*`, '');
+    }
+  }
+
+  if (block.item?.kind === 'trait') {
+    const implementations = findTraitImplIds(context.doc, block.item.name);
+    if (implementations.length > 0) {
+      pushHeading(page, subLevel, labels.implementations);
+      page.lines.push(...implementations.map(implId => {
+        const signature = context.doc[`doc.${implId}`].item?.signature ?? implId;
+        return `- ${renderEntryLink(implId, signature, page, context)}`;
+      }), '');
+    }
+  }
+
+  if (entry.see.length > 0) {
+    const links = entry.see.map(target => {
+      const targetId = resolveReferenceId(context.doc, target);
+      return targetId ? renderEntryLink(targetId, context.doc[`doc.${targetId}`].label, page, context) : target;
+    });
+    pushHeading(page, subLevel, labels.seeAlso);
+    page.lines.push(links.join(', '), '');
+  }
+}
+
+/**
+ * @doc renderTables renderTables
+ * @description Renders the type parameters, variants, fields and parameters tables of an entry
+ * @param page The page being rendered
+ * @param entry The structured entry
+ * @param level The heading level of the table titles
+ * @param context The rendering context
+ */
+function renderTables(page: MarkdownPage, entry: DocEntry, level: number, context: MarkdownContext): void {
+  const { labels } = context;
+
+  if (entry.generics.length > 0) {
+    pushHeading(page, level, labels.typeParameters);
+    page.lines.push(...renderTableHeader([labels.name, labels.description]));
+    for (const generic of entry.generics) {
+      const description = renderText(generic.description, page, context, escapeTableCell);
+      page.lines.push(`| ${codeSpan(generic.name)} | ${description} |`);
+    }
+    page.lines.push('');
+  }
+
+  if (entry.variants.length > 0) {
+    pushHeading(page, level, labels.variants);
+    page.lines.push(...renderTableHeader([labels.name, labels.fields, labels.description]));
+    for (const variant of entry.variants) {
+      const fields = variant.fields.map(field => renderType(field, page, context)).join(', ');
+      const description = renderText(variant.description, page, context, escapeTableCell);
+      page.lines.push(`| ${codeSpan(variant.name)} | ${fields} | ${description} |`);
+    }
+    page.lines.push('');
+  }
+
+  if (entry.fields.length > 0) {
+    renderTypedTable(page, labels.fields, entry.fields, level, context);
+  }
+
+  if (entry.params.length > 0) {
+    renderTypedTable(page, labels.parameters, entry.params, level, context);
+  }
+}
+
+/**
+ * @doc renderTypedTable renderTypedTable
+ * @description Renders a titled name, type, description table (parameters, struct fields)
+ * @description The Default column only appears when a row has a @default
+ * @param page The page being rendered
+ * @param title The table title
+ * @param rows The documented parameters or fields
+ * @param level The heading level of the title
+ * @param context The rendering context
+ */
+function renderTypedTable(
+  page: MarkdownPage,
+  title: string,
+  rows: DocParam[],
+  level: number,
+  context: MarkdownContext
+): void {
+  const { labels } = context;
+  const hasDefaults = rows.some(row => row.default !== undefined);
+  const columns = [labels.name, labels.type, labels.description, ...(hasDefaults ? [labels.default] : [])];
+
+  pushHeading(page, level, title);
+  page.lines.push(...renderTableHeader(columns));
+  for (const row of rows) {
+    const defaultCell = hasDefaults ? ` ${row.default !== undefined ? codeSpan(row.default) : ''} |` : '';
+    page.lines.push(
+      `| ${codeSpan(row.name)} | ${renderType(row.type, page, context)} | ${renderText(row.description, page, context, escapeTableCell)} |${defaultCell}`
+    );
+  }
+  page.lines.push('');
+}
+
+/**
+ * @doc renderTableHeader renderTableHeader
+ * @description Renders the header and delimiter rows of a table
+ * @param columns The column titles
+ * @returns The two table lines
+ */
+function renderTableHeader(columns: string[]): string[] {
+  return [
+    `| ${columns.map(escapeTableCell).join(' | ')} |`,
+    `|${columns.map(() => ' --- ').join('|')}|`,
+  ];
+}
+
+/**
+ * @doc renderDeprecation renderDeprecation
+ * @description Renders the deprecation blockquote of a block, or the hint inherited from a deprecated parent type
+ * @param block The documentable block
+ * @param page The page being rendered
+ * @param context The rendering context
+ * @returns The blockquote or an empty string
+ */
+function renderDeprecation(block: DocBlock, page: MarkdownPage, context: MarkdownContext): string {
+  const { labels } = context;
+  const deprecation = getDeprecation(block);
+
+  if (deprecation) {
+    const title = deprecation.since ? labels.deprecatedSince(deprecation.since) : labels.deprecated;
+    const message = linkifyReferences(
+      deprecation.message,
+      context.doc,
+      (key) => renderEntryLink(key, key, page, context)
+    );
+    return `> **${title}**${message ? `${labels.separator}${message}` : ''}`;
+  }
+
+  const parent = findParentBlock(context.doc, block);
+  if (parent && getDeprecation(parent)) {
+    return `> *${labels.parentDeprecated(parent.label)}*`;
+  }
+
+  return '';
+}
+
+/**
+ * @doc renderType renderType
+ * @description Renders a type as inline code, or with links when it mentions documented types
+ * @param type The type as written in the doc (e.g., Option<Calculator>)
+ * @param page The page being rendered
+ * @param context The rendering context
+ * @returns The type Markdown (safe inside a table cell)
+ */
+function renderType(type: string, page: MarkdownPage, context: MarkdownContext): string {
+  const tokens = type.match(/[A-Za-z_]\w*|[^A-Za-z_]+/g) || [];
+  const hasLinks = tokens.some(token => /^[A-Za-z_]/.test(token) && findTypeId(context.doc, token));
+
+  if (!hasLinks) {
+    return codeSpan(type);
+  }
+
+  return tokens
+    .map(token => {
+      const id = /^[A-Za-z_]/.test(token) ? findTypeId(context.doc, token) : null;
+      return id
+        ? renderEntryLink(id, token, page, context)
+        : escapeTableCell(token).replace(/</g, '&lt;').replace(/>/g, '&gt;');
+    })
+    .join('');
+}
+
+/**
+ * @doc renderText renderText
+ * @description Renders documentation text with {@link} tokens replaced by links
+ * @param text The documentation text
+ * @param page The page being rendered
+ * @param context The rendering context
+ * @param formatText Formats the text between links (default: unchanged, for prose)
+ * @returns The Markdown text
+ */
+function renderText(
+  text: string,
+  page: MarkdownPage,
+  context: MarkdownContext,
+  formatText: (text: string) => string = (text) => text
+): string {
+  return renderInlineLinks(
+    text,
+    context.doc,
+    (id, linkText) => renderEntryLink(id, linkText, page, context),
+    formatText
+  );
+}
+
+/**
+ * @doc renderEntryLink renderEntryLink
+ * @description Renders a relative link to an entry (anchor only when the entry is on the same page, the entry key in a single file)
+ * @param id The entry key
+ * @param text The link text
+ * @param page The page being rendered
+ * @param context The rendering context
+ * @returns The Markdown link, or the text if the entry has no page
+ */
+function renderEntryLink(id: string, text: string, page: MarkdownPage, context: MarkdownContext): string {
+  const file = context.layout.files.get(id);
+  if (file === undefined) {
+    return escapeLinkText(text);
+  }
+
+  const anchor = page.idAnchors ? id : context.anchors.get(id) ?? githubSlug(text);
+  const target = file === page.fileName ? `#${anchor}` : `${file}#${anchor}`;
+  return `[${escapeLinkText(text)}](${target})`;
+}
+
+/**
+ * @doc renderExample renderExample
+ * @description Renders an example as a fenced code block with the example language as info string
+ * @description Already fenced examples are kept verbatim, a bare opening fence only gets the language
+ * @param example The example
+ * @returns The example Markdown
+ */
+function renderExample(example: DocExample): string {
+  const content = example.content.trim();
+  if (/^(?:```|~~~)/.test(content)) {
+    return example.language ? content.replace(/^(```|~~~)[ \t]*(?=\n|$)/, `$1${example.language}`) : content;
+  }
+
+  return [`\`\`\`${example.language ?? ''}`, example.content, '```'].join('\n');
+}
+
+/**
+ * @doc getFenceLanguage getFenceLanguage
+ * @description Gets the code fence language of a block from its file extension
+ * @param block The documentable block
+ * @returns The language name (e.g., 'rust') or an empty string
+ */
+function getFenceLanguage(block: DocBlock): string {
+  return getLanguage(block.meta.ext) ?? '';
+}
+
+/**
+ * @doc createPage createPage
+ * @description Creates an empty page with its own heading slugger (duplicate headings get -1, -2... suffixes like on GitHub)
+ * @param fileName The page file name
+ * @param anchors The anchors of entry headings, shared by all pages
+ * @returns The page
+ */
+function createPage(fileName: string, anchors: Map<string, string>): MarkdownPage {
+  const counts = new Map<string, number>();
+
+  return {
+    fileName,
+    lines: [],
+    anchors,
+    slug: (text: string) => {
+      const base = githubSlug(text);
+      const count = counts.get(base) ?? 0;
+      counts.set(base, count + 1);
+      return count === 0 ? base : `${base}-${count}`;
+    },
+  };
+}
+
+/**
+ * @doc pushHeading pushHeading
+ * @description Appends an ATX heading to a page and records its anchor for an entry
+ * @description With idAnchors, entry headings are preceded by an explicit anchor named after the entry key
+ * @param page The page being rendered
+ * @param level The heading level (1-6)
+ * @param text The heading text
+ * @param id Optional entry key whose anchor is the heading
+ */
+function pushHeading(page: MarkdownPage, level: number, text: string, id?: string): void {
+  const slug = page.slug(text);
+  if (id && page.idAnchors) {
+    page.anchors.set(id, id);
+    page.lines.push(`<a id="${id.replace(/"/g, '&quot;')}"></a>`, '');
+  } else if (id) {
+    page.anchors.set(id, slug);
+  }
+  page.lines.push(`${'#'.repeat(level)} ${text}`, '');
+}
+
+/**
+ * @doc githubSlug githubSlug
+ * @description Computes the GitHub anchor of a heading (lowercase, punctuation removed, spaces as hyphens)
+ * @param text The heading text
+ * @returns The anchor, without #
+ */
+export function githubSlug(text: string): string {
+  return text
+    .trim()
+    .toLowerCase()
+    .replace(/[^\p{L}\p{N}\s_-]/gu, '')
+    .replace(/\s/g, '-');
+}
+
+/**
+ * @doc escapeTableCell escapeTableCell
+ * @description Escapes Markdown special characters (pipes, underscores, backticks, asterisks) so text stays in its table cell
+ * @param text The text to escape
+ * @returns The escaped text, on a single line
+ */
+export function escapeTableCell(text: string): string {
+  return text
+    .replace(/([\\|_`*])/g, '\\$1')
+    .replace(/\r?\n/g, '<br>');
+}
+
+/**
+ * @doc codeSpan codeSpan
+ * @description Wraps text in an inline code span usable in a table cell (pipes still split cells, so they are escaped)
+ * @param text The code text
+ * @returns The code span
+ */
+function codeSpan(text: string): string {
+  const escaped = text.replace(/\|/g, '\\|');
+  return escaped.includes('`') ? `\`\` ${escaped} \`\`` : `\`${escaped}\``;
+}
+
+/**
+ * @doc escapeLinkText escapeLinkText
+ * @description Escapes brackets and table separators in link text
+ * @param text The link text
+ * @returns The escaped link text
+ */
+function escapeLinkText(text: string): string {
+  return text.replace(/([[\]|])/g, '\\$1');
+}
+
+/**
+ * @doc joinLines joinLines
+ * @description Joins page lines, with a single trailing newline
+ * @param lines The page lines
+ * @returns The file content
+ */
+function joinLines(lines: string[]): string {
+  return `${lines.join('\n').trimEnd()}\n`;
+}
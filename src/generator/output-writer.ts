@@ -0,0 +1,162 @@
+/**
+ * @doc output_writer Output Writer
+ * @description Writes generated files to an output directory, skipping unchanged files and cleaning stale ones
+ */
+
+import { mkdir, readFile, readdir, rm, writeFile } from 'node:fs/promises';
+import path from 'node:path';
+import type { GeneratedFile } from '../types/index';
+
+/**
+ * @doc GENERATION_MARKER GENERATION_MARKER
+ * @description Text of the header comment written into each generated page, so --clean only removes files standardoc wrote
+ */
+export const GENERATION_MARKER = 'Generated by standardoc';
+
+// Marker comment by file extension (formats without comments, like JSON, are never marked)
+const MARKER_COMMENTS: Record<string, string> = {
+  '.html': `<!-- ${GENERATION_MARKER} -->`,
+  '.md': `<!-- ${GENERATION_MARKER} -->`,
+  '.js': `/* ${GENERATION_MARKER} */`,
+};
+
+/**
+ * @doc WriteResult WriteResult
+ * @description Files written (content changed) and stale files removed by a run, as absolute paths
+ */
+export interface WriteResult {
+  written: string[];
+  removed: string[];
+}
+
+/**
+ * @doc markGeneratedFile markGeneratedFile
+ * @description Adds the generation marker comment to a generated file
+ * @description The marker goes after the doctype of HTML files, on the first line otherwise
+ * @param file The generated file
+ * @returns The file with the marker, unchanged if its format has no comments
+ */
+export function markGeneratedFile(file: GeneratedFile): GeneratedFile {
+  const marker = MARKER_COMMENTS[path.extname(file.fileName)];
+  if (!marker) {
+    return file;
+  }
+
+  const doctype = file.content.match(/^<!DOCTYPE[^>]*>\n/i);
+  const content = doctype
+    ? `${doctype[0]}${marker}\n${file.content.slice(doctype[0].length)}`
+    : `${marker}\n${file.content}`;
+
+  return { fileName: file.fileName, content };
+}
+
+/**
+ * @doc hasGenerationMarker hasGenerationMarker
+ * @description Checks whether a file content starts with the generation marker (first two lines)
+ * @param content The file content
+ * @returns True if the file was generated by standardoc
+ */
+export function hasGenerationMarker(content: string): boolean {
+  const markers = Object.values(MARKER_COMMENTS);
+  return content.split('\n', 2).some(line => markers.includes(line.trim()));
+}
+
+/**
+ * @doc writeOutputFiles writeOutputFiles
+ * @description Writes generated files to a directory, leaving files whose content is unchanged untouched
+ * @description With clean, files of the directory that carry the generation marker but weren't produced by this run are removed; other files are never touched
+ * @param files The generated files, with names relative to the output directory
+ * @param outputDir The directory to write to
+ * @param options clean: remove stale generated files
+ * @returns The written and removed files
+ */
+export async function writeOutputFiles(
+  files: GeneratedFile[],
+  outputDir: string,
+  options: { clean?: boolean } = {}
+): Promise<WriteResult> {
+  const result: WriteResult = { written: [], removed: [] };
+  const produced = new Set<string>();
+
+  await mkdir(outputDir, { recursive: true });
+
+  for (const file of files) {
+    const filePath = path.resolve(outputDir, file.fileName);
+    produced.add(filePath);
+    if (await writeFileIfChanged(filePath, file.content)) {
+      result.written.push(filePath);
+    }
+  }
+
+  if (options.clean) {
+    for (const filePath of await listFiles(path.resolve(outputDir))) {
+      if (!produced.has(filePath) && await isGeneratedFile(filePath)) {
+        await rm(filePath, { force: true });
+        result.removed.push(filePath);
+      }
+    }
+  }
+
+  return result;
+}
+
+/**
+ * @doc writeFileIfChanged writeFileIfChanged
+ * @description Writes a file only if its content differs from the existing one
+ * @param filePath The file path
+ * @param content The new content
+ * @returns True if the file was written
+ */
+export async function writeFileIfChanged(filePath: string, content: string): Promise<boolean> {
+  try {
+    if (await readFile(filePath, 'utf-8') === content) {
+      return false;
+    }
+  } catch {
+    // Missing file: written below
+  }
+
+  await mkdir(path.dirname(filePath), { recursive: true });
+  await writeFile(filePath, content, 'utf-8');
+  return true;
+}
+
+/**
+ * @doc isGeneratedFile isGeneratedFile
+ * @description Checks whether a file carries the generation marker
+ * @param filePath The file path
+ * @returns True if the file was generated by standardoc (false if it can't be read)
+ */
+async function isGeneratedFile(filePath: string): Promise<boolean> {
+  if (!MARKER_COMMENTS[path.extname(filePath)]) {
+    return false;
+  }
+
+  try {
+    return hasGenerationMarker(await readFile(filePath, 'utf-8'));
+  } catch {
+    return false;
+  }
+}
+
+/**
+ * @doc listFiles listFiles
+ * @description Lists the files of a directory recursively
+ * @param dir The absolute directory path
+ * @returns The absolute file paths
+ */
+async function listFiles(dir: string): Promise<string[]> {
+  const entries = await readdir(dir, { withFileTypes: true });
+  const files: string[] = [];
+
+  for (const entry of entries) {
+    const entryPath = path.join(dir, entry.name);
+    if (entry.isDirectory()) {
+      files.push(...await listFiles(entryPath));
+    } else if (entry.isFile()) {
+      files.push(entryPath);
+    }
+  }
+
+  return files;
+}
@@ -0,0 +1,111 @@
+/**
+ * @doc search_index Search Index
+ * @description Builds the client-side search index and filter script of the HTML site
+ */
+
+import type { CanonicalDoc } from '../types/index';
+import { buildDocEntry } from './ast-generator';
+import type { DocLayout } from './doc-layout';
+import { findParentBlock, renderInlineLinks } from '../resolver/index';
+
+/**
+ * @doc SearchIndexEntry SearchIndexEntry
+ * @description Entry of the search index: display fields and the normalized text matched by queries
+ */
+export interface SearchIndexEntry {
+  id: string;
+  name: string;
+  kind: string;
+  description: string;
+  terms: string;
+}
+
+/**
+ * @doc SEARCH_SNIPPET_LENGTH SEARCH_SNIPPET_LENGTH
+ * @description Maximum length of the description snippet stored in the search index
+ */
+export const SEARCH_SNIPPET_LENGTH = 160;
+
+/**
+ * @doc SEARCH_SCRIPT SEARCH_SCRIPT
+ * @description Script filtering the sidebar as the user types
+ * @description Matches the query against the index terms, or the link text when the index can't be loaded (file:// pages)
+ */
+export const SEARCH_SCRIPT = `(function () {
+  var input = document.querySelector('.sidebar .search');
+  if (!input) return;
+  var items = Array.prototype.slice.call(document.querySelectorAll('.sidebar li[data-id]'));
+  var terms = {};
+  function normalize(text) {
+    return text.normalize('NFD').replace(/[\\u0300-\\u036f]/g, '').toLowerCase();
+  }
+  function filter() {
+    var query = normalize(input.value.trim());
+    items.forEach(function (item) {
+      var text = terms[item.getAttribute('data-id')] || normalize(item.textContent || '');
+      item.hidden = query !== '' && text.indexOf(query) === -1;
+    });
+    document.querySelectorAll('.sidebar section').forEach(function (section) {
+      section.hidden = query !== '' && !section.querySelector('li[data-id]:not([hidden])');
+    });
+  }
+  input.addEventListener('input', filter);
+  if (window.fetch) {
+    fetch('search-index.json')
+      .then(function (response) { return response.json(); })
+      .then(function (index) {
+        index.forEach(function (entry) { terms[entry.id] = entry.terms; });
+        filter();
+      })
+      .catch(function () {});
+  }
+})();
+`;
+
+/**
+ * @doc buildSearchIndex buildSearchIndex
+ * @description Builds the search index of a document, in sidebar order
+ * @param doc The canonical document containing all blocks
+ * @param layout The document layout
+ * @returns The search index entries
+ */
+export function buildSearchIndex(doc: CanonicalDoc, layout: DocLayout): SearchIndexEntry[] {
+  return layout.groups.flatMap(group => group.entries.map(({ id, block }) => {
+    const entry = buildDocEntry(id, block, findParentBlock(doc, block));
+    const description = toSnippet(renderInlineLinks(entry.description, doc, (_id, text) => text));
+
+    return {
+      id,
+      name: entry.name,
+      kind: block.item?.kind ?? '',
+      description,
+      terms: normalizeSearchText([entry.name, id, ...entry.aliases, group.name, description].join(' ')),
+    };
+  }));
+}
+
+/**
+ * @doc normalizeSearchText normalizeSearchText
+ * @description Normalizes text for matching: lowercase, diacritics stripped ("Calculé" becomes "calcule")
+ * @param text The text to normalize
+ * @returns The normalized text
+ */
+export function normalizeSearchText(text: string): string {
+  return text.normalize('NFD').replace(/[\u0300-\u036f]/g, '').toLowerCase();
+}
+
+/**
+ * @doc toSnippet toSnippet
+ * @description Shortens a description to SEARCH_SNIPPET_LENGTH characters, cutting at a word boundary
+ * @param text The description
+ * @returns The snippet
+ */
+function toSnippet(text: string): string {
+  if (text.length <= SEARCH_SNIPPET_LENGTH) {
+    return text;
+  }
+
+  const cut = text.slice(0, SEARCH_SNIPPET_LENGTH);
+  const lastSpace = cut.lastIndexOf(' ');
+  return `${(lastSpace > 0 ? cut.slice(0, lastSpace) : cut).trimEnd()}…`;
+}
@@ -0,0 +1,54 @@
+/**
+ * @doc source_link Source Links
+ * @description Builds links from documented items to their definition on a Git host (--source-url)
+ */
+
+import type { DocBlock } from '../types/index';
+import { StandardocError } from '../types/index';
+
+// Current source URL template (null: no source links)
+let SOURCE_URL_TEMPLATE: string | null = null;
+
+/**
+ * @doc setSourceUrlTemplate setSourceUrlTemplate
+ * @description Sets the template of source links, e.g. https://github.com/me/repo/blob/main/{path}#L{line}
+ * @param template The template with {path} and {line} placeholders, or null to disable source links
+ * @throws StandardocError if the template has no {path} placeholder
+ */
+export function setSourceUrlTemplate(template: string | null): void {
+  if (template !== null && !template.includes('{path}')) {
+    throw new StandardocError(`Invalid source URL template '${template}': missing {path} placeholder`);
+  }
+  SOURCE_URL_TEMPLATE = template;
+}
+
+/**
+ * @doc getSourceUrlTemplate getSourceUrlTemplate
+ * @description Gets the template of source links
+ * @returns The template, or null if source links are disabled
+ */
+export function getSourceUrlTemplate(): string | null {
+  return SOURCE_URL_TEMPLATE;
+}
+
+/**
+ * @doc getSourceUrl getSourceUrl
+ * @description Builds the source link of a block: relative file path (with / separators) and line of the item definition
+ * @description Falls back to the line of the doc comment when no item was detected
+ * @param block The documentable block
+ * @returns The URL, or null if source links are disabled
+ */
+export function getSourceUrl(block: DocBlock): string | null {
+  if (!SOURCE_URL_TEMPLATE) {
+    return null;
+  }
+
+  const filePath = block.meta.path
+    .replace(/\\/g, '/')
+    .split('/')
+    .map(segment => encodeURIComponent(segment))
+    .join('/');
+  const line = String(block.item?.line ?? block.meta.line);
+
+  return SOURCE_URL_TEMPLATE.replace(/\{path\}/g, filePath).replace(/\{line\}/g, line);
+}
@@ -5,9 +5,16 @@
 
 export * from './types/index';
 export * from './parser/index';
+export * from './analyzer/index';
 export * from './extractor/index';
+export * from './diagnostics/index';
+export * from './resolver/index';
 export * from './generator/index';
 export * from './dsl/index';
 export * from './transformer/index';
 export * from './scanner/index';
+export * from './checker/index';
+export * from './tester/index';
+export * from './differ/index';
+export * from './watcher/index';
 export * from './config/index';
@@ -6,84 +6,88 @@
 import type { CommentStyle } from '../types/index';
 import type { CommentPatternConfig } from '../config/config-loader';
 
-export const COMMENT_STYLES: Record<string, CommentPatternConfig> = {
+// Comment syntax shared by C-like languages (C, C++, Rust, JavaScript, TypeScript...)
+const C_STYLE_COMMENTS: CommentPatternConfig = {
+  single: ['//'],
+  multi: { start: '/*', end: '*/' },
+  docSingle: ['///', '//!'],
+  docMulti: { start: '/**', end: '*/' },
+};
+
+/**
+ * @doc LANGUAGE_FRONTENDS LANGUAGE_FRONTENDS
+ * @description Comment syntax per language, with the file extensions selecting it
+ * @description The tag extraction downstream is identical for all languages
+ */
+export const LANGUAGE_FRONTENDS: Record<string, { extensions: string[]; comments: CommentPatternConfig }> = {
   lua: {
-    single: ['--'],
-    docSingle: ['---'],
-  },
-  js: {
-    single: ['//'],
-    multi: { start: '/*', end: '*/' },
-    docSingle: ['///'],
-    docMulti: { start: '/**', end: '*/' },
-  },
-  ts: {
-    single: ['//'],
-    multi: { start: '/*', end: '*/' },
-    docSingle: ['///'],
-    docMulti: { start: '/**', end: '*/' },
+    extensions: ['lua'],
+    comments: { single: ['--'], docSingle: ['---'] },
   },
-  jsx: {
-    single: ['//'],
-    multi: { start: '/*', end: '*/' },
-    docSingle: ['///'],
-    docMulti: { start: '/**', end: '*/' },
+  javascript: {
+    extensions: ['js', 'jsx', 'mjs', 'cjs'],
+    comments: C_STYLE_COMMENTS,
   },
-  tsx: {
-    single: ['//'],
-    multi: { start: '/*', end: '*/' },
-    docSingle: ['///'],
-    docMulti: { start: '/**', end: '*/' },
+  typescript: {
+    extensions: ['ts', 'tsx', 'mts', 'cts'],
+    comments: C_STYLE_COMMENTS,
   },
-
   c: {
-    single: ['//'],
-    multi: { start: '/*', end: '*/' },
+    extensions: ['c', 'h'],
+    comments: C_STYLE_COMMENTS,
   },
   cpp: {
-    single: ['//'],
-    multi: { start: '/*', end: '*/' },
-  },
-  h: {
-    single: ['//'],
-    multi: { start: '/*', end: '*/' },
-  },
-  hpp: {
-    single: ['//'],
-    multi: { start: '/*', end: '*/' },
-  },
-
-  py: {
-    single: ['#'],
+    extensions: ['cpp', 'cc', 'cxx', 'hpp', 'hh', 'hxx'],
+    comments: C_STYLE_COMMENTS,
   },
-
-  rs: {
-    single: ['//'],
-    multi: { start: '/*', end: '*/' },
-    docSingle: ['///'],
-    docMulti: { start: '/**', end: '*/' },
+  rust: {
+    extensions: ['rs'],
+    comments: C_STYLE_COMMENTS,
   },
-
   hlsl: {
-    single: ['//'],
-    multi: { start: '/*', end: '*/' },
-  },
-
-  sh: {
-    single: ['#'],
+    extensions: ['hlsl'],
+    comments: { single: ['//'], multi: { start: '/*', end: '*/' } },
   },
-  bash: {
-    single: ['#'],
+  python: {
+    extensions: ['py'],
+    comments: { single: ['#'] },
   },
-
-  txt: {
-    single: [],
+  shell: {
+    extensions: ['sh', 'bash'],
+    comments: { single: ['#'] },
   },
-  md: {
-    single: [],
+  text: {
+    extensions: ['txt', 'md'],
+    comments: { single: [] },
   },
 };
 
+/**
+ * @doc COMMENT_STYLES COMMENT_STYLES
+ * @description Default comment patterns by file extension, derived from LANGUAGE_FRONTENDS
+ */
+export const COMMENT_STYLES: Record<string, CommentPatternConfig> = Object.fromEntries(
+  Object.values(LANGUAGE_FRONTENDS).flatMap(({ extensions, comments }) =>
+    extensions.map(ext => [ext, comments])
+  )
+);
+
+/**
+ * @doc getLanguage getLanguage
+ * @description Gets the language of a file extension
+ * @param extension File extension (e.g., '.rs', 'h')
+ * @returns The language name (e.g., 'rust', 'c') or null if the extension is unknown
+ */
+export function getLanguage(extension: string): string | null {
+  const ext = extension.toLowerCase().replace(/^\./, '');
+  for (const [language, frontend] of Object.entries(LANGUAGE_FRONTENDS)) {
+    if (frontend.extensions.includes(ext)) {
+      return language;
+    }
+  }
+  return null;
+}
+
 let customPatterns: Record<string, CommentPatternConfig> | null = null;
 
 /**
@@ -95,12 +99,42 @@ export function setCustomPatterns(patterns: Record<string, CommentPatternConfig>
   customPatterns = patterns;
 }
 
+// Language used for extensions without known comment syntax (from config)
+let defaultLanguage: string | null = null;
+
+/**
+ * @doc setDefaultLanguage setDefaultLanguage
+ * @description Sets the language used for files whose extension has no known comment syntax
+ * @param language The language name (e.g., 'rust') or null to disable
+ */
+export function setDefaultLanguage(language: string | null): void {
+  defaultLanguage = language;
+}
+
+/**
+ * @doc getDefaultLanguage getDefaultLanguage
+ * @description Gets the language used for files whose extension has no known comment syntax
+ * @returns The language name or null if none is set
+ */
+export function getDefaultLanguage(): string | null {
+  return defaultLanguage;
+}
+
+/**
+ * @doc getCustomPatterns getCustomPatterns
+ * @description Gets the custom comment patterns set from config
+ * @returns The custom patterns or null if none are set
+ */
+export function getCustomPatterns(): Record<string, CommentPatternConfig> | null {
+  return customPatterns;
+}
+
 /**
  * @doc detectCommentStyle detectCommentStyle
  * @description Detects comment style for a given language/extension
  * @description Uses custom patterns if available, otherwise falls back to defaults
  * @param extension File extension (e.g., '.ts', '.lua')
- * @param language Optional language override
+ * @param language Optional language override (default: the language set from config)
  * @returns Comment style configuration or null if not supported
  */
 export function detectCommentStyle(
@@ -113,7 +147,7 @@ export function detectCommentStyle(
   docMulti?: { start: string; end: string };
 } | null {
   const ext = extension.toLowerCase().replace(/^\./, '');
-  const lang = language?.toLowerCase();
+  const lang = (language ?? defaultLanguage ?? undefined)?.toLowerCase();
 
   // Check custom patterns first
   if (customPatterns) {
@@ -129,8 +163,9 @@ export function detectCommentStyle(
     }
   }
 
-  // Fall back to defaults
-  const defaultPattern = COMMENT_STYLES[ext] || (lang ? COMMENT_STYLES[lang] : undefined);
+  // Fall back to defaults (language override matches a language name or an extension)
+  const defaultPattern = COMMENT_STYLES[ext]
+    || (lang ? LANGUAGE_FRONTENDS[lang]?.comments || COMMENT_STYLES[lang] : undefined);
   if (defaultPattern) {
     return {
       single: defaultPattern.single || ['//'],
@@ -178,24 +213,26 @@ export function extractCommentPrefix(
     }
   }
 
-  if (styles.multi) {
-    if (trimmed.includes(styles.multi.start)) {
-      return { prefix: styles.multi.start, style: 'multi-line' };
-    }
-  }
-
+  // Doc block comments first: '/**' also contains the plain '/*' start
   if (styles.docMulti) {
     if (trimmed.includes(styles.docMulti.start)) {
       return { prefix: styles.docMulti.start, style: 'doc-multi' };
     }
   }
 
+  if (styles.multi) {
+    if (trimmed.includes(styles.multi.start)) {
+      return { prefix: styles.multi.start, style: 'multi-line' };
+    }
+  }
+
   return null;
 }
 
 /**
  * @doc cleanCommentContent cleanCommentContent
  * @description Cleans comment content by removing prefix
+ * @description Only the single space following the prefix is removed so code indentation is preserved
  * @param line The comment line to clean
  * @param prefix The comment prefix to remove
  * @param style The comment style
@@ -208,7 +245,7 @@ export function cleanCommentContent(
 ): string {
   if (style === 'single-line' || style === 'doc-single') {
     const cleaned = line.replace(new RegExp(`^\\s*${escapeRegex(prefix)}`), '');
-    return cleaned.trimStart();
+    return cleaned.replace(/^ /, '');
   }
 
   return line;
@@ -29,9 +29,16 @@ export function parseComments(
   }
 
   const lines = content.split(/\r?\n/);
+  const lineOffsets = getLineOffsets(content);
   const comments: ParsedComment[] = [];
   let inMultiLine = false;
-  let multiLineStart: { line: number; column: number; prefix: string; style: ParsedComment['style'] } | null = null;
+  let multiLineStart: {
+    line: number;
+    column: number;
+    offset: number;
+    prefix: string;
+    style: ParsedComment['style'];
+  } | null = null;
   let multiLineContent: string[] = [];
 
   for (let i = 0; i < lines.length; i++) {
@@ -44,22 +51,18 @@ export function parseComments(
       const multiEnd = styles.multi?.end || styles.docMulti?.end;
       if (multiEnd && line.includes(multiEnd)) {
         const fullContent = multiLineContent.join('\n');
-        let cleaned = fullContent
-          .replace(new RegExp(`^.*?${escapeRegex(multiLineStart.prefix)}`), '')
-          .replace(new RegExp(`${escapeRegex(multiEnd)}.*$`), '');
-
-        cleaned = cleaned
-          .split('\n')
-          .map(l => {
-            return l.replace(/^\s*\*\s?/, '').trimEnd();
-          })
-          .join('\n')
-          .trim();
+        const { content: cleaned, skippedLines } = cleanMultiLineContent(
+          fullContent,
+          multiLineStart.prefix,
+          multiEnd
+        );
 
         comments.push({
           raw: fullContent,
           line: multiLineStart.line,
+          contentLine: multiLineStart.line + skippedLines,
           column: multiLineStart.column,
+          offset: multiLineStart.offset,
           style: multiLineStart.style,
           content: cleaned,
         });
@@ -77,10 +80,11 @@ export function parseComments(
     if (commentInfo) {
       const { prefix, style } = commentInfo;
       const column = line.indexOf(prefix) + 1;
+      const offset = lineOffsets[i] + Buffer.byteLength(line.slice(0, column - 1), 'utf8');
 
       if (style === 'multi-line' || style === 'doc-multi') {
         inMultiLine = true;
-        multiLineStart = { line: lineNumber, column, prefix, style };
+        multiLineStart = { line: lineNumber, column, offset, prefix, style };
         multiLineContent = [line];
 
         const multiEnd = (style === 'multi-line' ? styles.multi : styles.docMulti)?.end;
@@ -94,7 +98,9 @@ export function parseComments(
           comments.push({
             raw: line,
             line: lineNumber,
+            contentLine: lineNumber,
             column,
+            offset,
             style,
             content: cleaned,
           });
@@ -109,7 +115,9 @@ export function parseComments(
         comments.push({
           raw: line,
           line: lineNumber,
+          contentLine: lineNumber,
           column,
+          offset,
           style,
           content: cleaned,
         });
@@ -119,28 +127,19 @@ export function parseComments(
 
   if (inMultiLine && multiLineStart && multiLineContent.length > 0) {
     const fullContent = multiLineContent.join('\n');
-    const prefix = multiLineStart.prefix;
     const multiEnd = (multiLineStart.style === 'multi-line' ? styles.multi : styles.docMulti)?.end;
-
-    let cleaned = fullContent
-      .replace(new RegExp(`^.*?${escapeRegex(prefix)}`), '');
-
-    if (multiEnd) {
-      cleaned = cleaned.replace(new RegExp(`${escapeRegex(multiEnd)}.*$`), '');
-    }
-
-    cleaned = cleaned
-      .split('\n')
-      .map(line => {
-        return line.replace(/^\s*\*\s?/, '').trimEnd();
-      })
-      .join('\n')
-      .trim();
+    const { content: cleaned, skippedLines } = cleanMultiLineContent(
+      fullContent,
+      multiLineStart.prefix,
+      multiEnd
+    );
 
     comments.push({
       raw: fullContent,
       line: multiLineStart.line,
+      contentLine: multiLineStart.line + skippedLines,
       column: multiLineStart.column,
+      offset: multiLineStart.offset,
       style: multiLineStart.style,
       content: cleaned,
     });
@@ -149,6 +148,61 @@ export function parseComments(
   return comments;
 }
 
+/**
+ * @doc cleanMultiLineContent cleanMultiLineContent
+ * @description Removes the delimiters and leading * alignment characters of a block comment
+ * @description Blank lines at the start and end are dropped; skippedLines counts the leading ones
+ * @param fullContent The raw block comment
+ * @param prefix The comment start delimiter
+ * @param end The comment end delimiter, if any
+ * @returns The cleaned content and the number of leading lines dropped
+ */
+function cleanMultiLineContent(
+  fullContent: string,
+  prefix: string,
+  end?: string
+): { content: string; skippedLines: number } {
+  let cleaned = fullContent.replace(new RegExp(`^.*?${escapeRegex(prefix)}`), '');
+
+  if (end) {
+    cleaned = cleaned.replace(new RegExp(`${escapeRegex(end)}.*$`), '');
+  }
+
+  const lines = cleaned
+    .split('\n')
+    .map(line => line.replace(/^\s*\*\s?/, '').trimEnd());
+
+  let skippedLines = 0;
+  while (skippedLines < lines.length && lines[skippedLines].trim() === '') {
+    skippedLines++;
+  }
+
+  return {
+    content: lines.slice(skippedLines).join('\n').trim(),
+    skippedLines,
+  };
+}
+
+/**
+ * @doc getLineOffsets getLineOffsets
+ * @description Computes the UTF-8 byte offset of the start of each line
+ * @param content The file content
+ * @returns The byte offset of each line, indexed by 0-based line number
+ */
+function getLineOffsets(content: string): number[] {
+  const offsets = [0];
+  let offset = 0;
+
+  for (const char of content) {
+    offset += Buffer.byteLength(char, 'utf8');
+    if (char === '\n') {
+      offsets.push(offset);
+    }
+  }
+
+  return offsets;
+}
+
 /**
  * @doc escapeRegex escapeRegex
  * @description Escapes special regex characters in a string
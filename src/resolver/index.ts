@@ -0,0 +1,7 @@
+/**
+ * @doc resolver Resolver Module
+ * @description Resolves relations and references between documentable blocks
+ */
+
+export * from './reference-resolver';
+export * from './version-resolver';
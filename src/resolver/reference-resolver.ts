@@ -0,0 +1,305 @@
+/**
+ * @doc reference_resolver Reference Resolver
+ * @description Resolves parent types, module paths and @doc key references between documentable blocks
+ * @description References may use the key of an entry or one of its @alias names
+ */
+
+import type { CanonicalDoc, DocBlock, ItemKind } from '../types/index';
+import { reportWarning } from '../diagnostics/index';
+
+/**
+ * @doc TYPE_KINDS TYPE_KINDS
+ * @description Item kinds that can own methods and get their own page
+ */
+export const TYPE_KINDS: ItemKind[] = ['struct', 'enum', 'trait', 'class', 'interface'];
+
+// Candidate @doc keys inside free text (e.g. "use calculator_sum instead")
+const KEY_CANDIDATE_REGEX = /[A-Za-z_]\w*(?:\.\w+)*/g;
+
+// Token starting tags, so inline links are {<prefix>link ...} (set with the tag prefix, see setTagPrefix)
+let INLINE_LINK_PREFIX = '@';
+// Inline links in free text: {@link id} or {@link id custom text}; a leading backslash keeps the token literal
+let INLINE_LINK_REGEX = buildInlineLinkRegex(INLINE_LINK_PREFIX);
+
+// File names that don't add a segment to the module path
+const MODULE_ROOT_FILES = ['mod', 'lib', 'main', 'index'];
+
+/**
+ * @doc setInlineLinkPrefix setInlineLinkPrefix
+ * @description Sets the tag prefix of inline links ({!link id} with "!"); called by setTagPrefix so all tags share it
+ * @param prefix The tag prefix (default: "@")
+ */
+export function setInlineLinkPrefix(prefix: string): void {
+  INLINE_LINK_PREFIX = prefix;
+  INLINE_LINK_REGEX = buildInlineLinkRegex(prefix);
+}
+
+/**
+ * @doc getInlineLinkPrefix getInlineLinkPrefix
+ * @description Gets the tag prefix of inline links
+ * @returns The tag prefix
+ */
+export function getInlineLinkPrefix(): string {
+  return INLINE_LINK_PREFIX;
+}
+
+/**
+ * @doc buildInlineLinkRegex buildInlineLinkRegex
+ * @description Builds the regex matching inline links: {<prefix>link id} or {<prefix>link id text}, with an optional escaping backslash
+ * @param prefix The tag prefix
+ * @returns The inline link regex (global)
+ */
+function buildInlineLinkRegex(prefix: string): RegExp {
+  const escaped = prefix.replace(/[.*+?^${}()|[\]\\]/g, '\\$&');
+  return new RegExp(`(\\\\?)\\{${escaped}link\\s+([^\\s}]+)(?:\\s+([^}]*))?\\}`, 'g');
+}
+
+/**
+ * @doc findTypeId findTypeId
+ * @description Finds the key of the documented type (struct, class...) with a given name
+ * @param doc The canonical document containing all blocks
+ * @param name The type name as written in the source
+ * @returns The key of the type block or null if the type is not documented
+ */
+export function findTypeId(doc: CanonicalDoc, name: string): string | null {
+  for (const [docKey, candidate] of Object.entries(doc)) {
+    if (candidate.item && candidate.item.name === name && TYPE_KINDS.includes(candidate.item.kind)) {
+      return docKey.slice('doc.'.length);
+    }
+  }
+
+  return null;
+}
+
+/**
+ * @doc findParentId findParentId
+ * @description Finds the key of the documented type owning a method block
+ * @param doc The canonical document containing all blocks
+ * @param block The method block
+ * @returns The key of the parent block or null if the block has no documented parent
+ */
+export function findParentId(doc: CanonicalDoc, block: DocBlock): string | null {
+  const parent = block.item?.parent;
+  if (!parent) {
+    return null;
+  }
+
+  const id = findTypeId(doc, parent);
+  return id && doc[`doc.${id}`] !== block ? id : null;
+}
+
+/**
+ * @doc findParentBlock findParentBlock
+ * @description Finds the documented type (struct, class...) owning a method block
+ * @param doc The canonical document containing all blocks
+ * @param block The method block
+ * @returns The parent block or null if the block has no documented parent
+ */
+export function findParentBlock(doc: CanonicalDoc, block: DocBlock): DocBlock | null {
+  const id = findParentId(doc, block);
+  return id ? doc[`doc.${id}`] : null;
+}
+
+/**
+ * @doc findTraitImplIds findTraitImplIds
+ * @description Finds the keys of the documented implementations of a trait (impl Trait for Type blocks)
+ * @param doc The canonical document containing all blocks
+ * @param traitName The trait name as written in the source
+ * @returns The keys of the impl blocks, in document order
+ */
+export function findTraitImplIds(doc: CanonicalDoc, traitName: string): string[] {
+  return Object.entries(doc)
+    .filter(([, block]) => block.item?.kind === 'impl' && block.item.trait === traitName)
+    .map(([docKey]) => docKey.slice('doc.'.length));
+}
+
+/**
+ * @doc getAliases getAliases
+ * @description Gets the @alias names of a block (additional keys its references and search resolve)
+ * @param block The documentable block
+ * @returns The alias names, in tag order
+ */
+export function getAliases(block: DocBlock): string[] {
+  const alias = block.alias;
+  return (Array.isArray(alias) ? alias : [])
+    .map(([name = '']) => name)
+    .filter(name => name.length > 0);
+}
+
+/**
+ * @doc resolveReferenceId resolveReferenceId
+ * @description Resolves a reference (@see, {@link}, key in free text) to the key of an entry, by key then by @alias
+ * @param doc The canonical document containing all blocks
+ * @param id The referenced key or alias
+ * @returns The key of the referenced entry, or null if no entry has this key or alias
+ */
+export function resolveReferenceId(doc: CanonicalDoc, id: string): string | null {
+  if (doc[`doc.${id}`]) {
+    return id;
+  }
+
+  for (const [docKey, block] of Object.entries(doc)) {
+    if (getAliases(block).includes(id)) {
+      return docKey.slice('doc.'.length);
+    }
+  }
+
+  return null;
+}
+
+/**
+ * @doc validateReferences validateReferences
+ * @description Reports a warning for each @see tag and {@link} token referencing an unknown key
+ * @description Runs on the merged document so references across files resolve
+ * @param doc The canonical document containing all blocks
+ * @returns The number of unresolved references
+ */
+export function validateReferences(doc: CanonicalDoc): number {
+  let unresolved = 0;
+
+  for (const block of Object.values(doc)) {
+    const see = block.see;
+    for (const [id = ''] of Array.isArray(see) ? see : []) {
+      if (id && !resolveReferenceId(doc, id)) {
+        unresolved++;
+        reportWarning(
+          `unresolved ${INLINE_LINK_PREFIX}see reference '${id}' in '${block.label}'`,
+          block.meta.path,
+          block.meta.line
+        );
+      }
+    }
+
+    for (const [tag, data] of Object.entries(block)) {
+      // Examples are code: {@link} tokens in them are not links
+      if (tag === 'see' || tag === 'example' || !Array.isArray(data)) {
+        continue;
+      }
+
+      for (const field of data.flat()) {
+        for (const id of findInlineLinks(field)) {
+          if (!resolveReferenceId(doc, id)) {
+            unresolved++;
+            reportWarning(
+              `unresolved {${INLINE_LINK_PREFIX}link ${id}} in '${block.label}'`,
+              block.meta.path,
+              block.meta.line
+            );
+          }
+        }
+      }
+    }
+  }
+
+  return unresolved;
+}
+
+/**
+ * @doc getModulePath getModulePath
+ * @description Derives the module path of a block from its file path, followed by the inline modules enclosing its item
+ * @description Example: src/math/calc.rs becomes math::calc, src/lib.rs becomes crate, mod arithmetic { ... } in src/lib.rs becomes arithmetic
+ * @param block The documentable block
+ * @returns The module path
+ */
+export function getModulePath(block: DocBlock): string {
+  const segments = block.meta.path
+    .replace(/\\/g, '/')
+    .replace(/\.[^./]+$/, '')
+    .split('/')
+    .filter(segment => segment && segment !== '.');
+
+  if (segments[0] === 'src') {
+    segments.shift();
+  }
+
+  if (segments.length > 0 && MODULE_ROOT_FILES.includes(segments[segments.length - 1])) {
+    segments.pop();
+  }
+
+  segments.push(...(block.item?.modules ?? []));
+
+  return segments.length > 0 ? segments.join('::') : 'crate';
+}
+
+/**
+ * @doc findInlineLinks findInlineLinks
+ * @description Finds the keys targeted by the {@link} tokens of a text (escaped tokens are ignored)
+ * @param text The text to scan
+ * @returns The linked keys, in text order
+ */
+export function findInlineLinks(text: string): string[] {
+  return [...text.matchAll(INLINE_LINK_REGEX)]
+    .filter(([, escape]) => !escape)
+    .map(([, , id]) => id);
+}
+
+/**
+ * @doc renderInlineLinks renderInlineLinks
+ * @description Replaces the {@link id} and {@link id text} tokens of a text with links
+ * @description Unknown keys are left as literal text (validateReferences warns about them); \{@link ...} renders as literal braces
+ * @param text The text to scan
+ * @param doc The canonical document containing all blocks
+ * @param formatLink Formats a link for a resolved key, aliases being resolved to the entry key (text is the custom text, or the label of the target)
+ * @param formatText Formats the text between links (default: unchanged)
+ * @returns The text with resolved tokens replaced by links
+ */
+export function renderInlineLinks(
+  text: string,
+  doc: CanonicalDoc,
+  formatLink: (key: string, text: string, block: DocBlock) => string,
+  formatText: (text: string) => string = (text) => text
+): string {
+  let result = '';
+  let last = 0;
+
+  for (const match of text.matchAll(INLINE_LINK_REGEX)) {
+    const [token, escape, reference, customText] = match;
+    const index = match.index ?? 0;
+    const id = escape ? null : resolveReferenceId(doc, reference);
+
+    result += formatText(text.slice(last, index));
+    if (escape) {
+      result += formatText(token.slice(1));
+    } else if (id) {
+      const block = doc[`doc.${id}`];
+      result += formatLink(id, customText?.trim() || block.label, block);
+    } else {
+      result += formatText(token);
+    }
+    last = index + token.length;
+  }
+
+  return result + formatText(text.slice(last));
+}
+
+/**
+ * @doc linkifyReferences linkifyReferences
+ * @description Replaces @doc keys (or @alias names) found in free text with links
+ * @param text The text to scan
+ * @param doc The canonical document containing all blocks
+ * @param formatLink Formats a link for a resolved key, aliases being resolved to the entry key (default: Markdown link to #key)
+ * @param formatText Formats the text between links (default: unchanged)
+ * @returns The text with known keys replaced by links
+ */
+export function linkifyReferences(
+  text: string,
+  doc: CanonicalDoc,
+  formatLink: (key: string, block: DocBlock) => string = (key) => `[${key}](#${key})`,
+  formatText: (text: string) => string = (text) => text
+): string {
+  let result = '';
+  let last = 0;
+
+  for (const match of text.matchAll(KEY_CANDIDATE_REGEX)) {
+    const word = match[0];
+    const index = match.index ?? 0;
+    const id = resolveReferenceId(doc, word);
+
+    if (id) {
+      result += formatText(text.slice(last, index)) + formatLink(id, doc[`doc.${id}`]);
+      last = index + word.length;
+    }
+  }
+
+  return result + formatText(text.slice(last));
+}
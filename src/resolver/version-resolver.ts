@@ -0,0 +1,113 @@
+/**
+ * @doc version_resolver Version Resolver
+ * @description Parses and compares @since versions, and filters entries by minimum version
+ */
+
+import type { CanonicalDoc } from '../types/index';
+import { reportInfo } from '../diagnostics/index';
+
+/**
+ * @doc ParsedVersion ParsedVersion
+ * @description Version numbers of a @since tag (missing numbers are 0), prerelease is null for a release
+ */
+export interface ParsedVersion {
+  major: number;
+  minor: number;
+  patch: number;
+  prerelease: string | null;
+}
+
+// Strict semantic version (major.minor.patch[-prerelease][+build])
+const SEMVER_REGEX = /^(?:0|[1-9]\d*)\.(?:0|[1-9]\d*)\.(?:0|[1-9]\d*)(?:-[0-9A-Za-z-]+(?:\.[0-9A-Za-z-]+)*)?(?:\+[0-9A-Za-z-]+(?:\.[0-9A-Za-z-]+)*)?$/;
+
+// Lenient version: optional "v", 1 to 3 numbers, optional prerelease and build
+const LENIENT_VERSION_REGEX = /^[vV]?(\d+)(?:\.(\d+))?(?:\.(\d+))?(?:-([0-9A-Za-z.-]+))?(?:\+[0-9A-Za-z.-]+)?$/;
+
+/**
+ * @doc isSemver isSemver
+ * @description Checks whether a version is a valid semantic version (e.g. 1.2.0, 2.0.0-beta.1)
+ * @param version The version text
+ * @returns True if the version is valid semver
+ */
+export function isSemver(version: string): boolean {
+  return SEMVER_REGEX.test(version);
+}
+
+/**
+ * @doc parseVersion parseVersion
+ * @description Parses a version leniently: "v1.2", "1.2" and "1" are read as 1.2.0, 1.2.0 and 1.0.0
+ * @param version The version text
+ * @returns The parsed version or null if it can't be read
+ */
+export function parseVersion(version: string): ParsedVersion | null {
+  const match = version.trim().match(LENIENT_VERSION_REGEX);
+  if (!match) {
+    return null;
+  }
+
+  const [, major, minor = '0', patch = '0', prerelease] = match;
+  return {
+    major: Number(major),
+    minor: Number(minor),
+    patch: Number(patch),
+    prerelease: prerelease ?? null,
+  };
+}
+
+/**
+ * @doc compareVersions compareVersions
+ * @description Compares two parsed versions; a prerelease comes before its release
+ * @param a The first version
+ * @param b The second version
+ * @returns A negative number if a is older, 0 if equal, a positive number if a is newer
+ */
+export function compareVersions(a: ParsedVersion, b: ParsedVersion): number {
+  const numbers = a.major - b.major || a.minor - b.minor || a.patch - b.patch;
+  if (numbers !== 0) {
+    return numbers;
+  }
+
+  if (a.prerelease === b.prerelease) {
+    return 0;
+  }
+  if (a.prerelease === null) {
+    return 1;
+  }
+  if (b.prerelease === null) {
+    return -1;
+  }
+
+  return a.prerelease.localeCompare(b.prerelease, 'en', { numeric: true });
+}
+
+/**
+ * @doc filterByMinVersion filterByMinVersion
+ * @description Removes the entries whose @since version is older than a minimum version ("what's new" docs)
+ * @description Entries without @since, or with a version that can't be read, are always kept; the latter are reported as infos
+ * @param doc The canonical document
+ * @param minVersion The minimum version
+ * @returns A new document with the kept entries, in the same order
+ */
+export function filterByMinVersion(doc: CanonicalDoc, minVersion: ParsedVersion): CanonicalDoc {
+  const filtered: CanonicalDoc = {};
+
+  for (const [docKey, block] of Object.entries(doc)) {
+    const since = block.since;
+    const written = Array.isArray(since) ? since[0]?.[0] ?? '' : '';
+    const version = written ? parseVersion(written) : null;
+
+    if (written && !version) {
+      reportInfo(
+        `'${block.label}' kept by --min-version: its since version '${written}' can't be read`,
+        block.meta.path,
+        block.meta.line
+      );
+    }
+
+    if (!version || compareVersions(version, minVersion) >= 0) {
+      filtered[docKey as keyof CanonicalDoc] = block;
+    }
+  }
+
+  return filtered;
+}
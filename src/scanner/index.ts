@@ -4,3 +4,6 @@
  */
 
 export * from './workspace-scanner';
+export * from './source-parser';
+export * from './parallel-parser';
+export * from './parse-cache';
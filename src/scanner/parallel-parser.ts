@@ -0,0 +1,168 @@
+/**
+ * @doc parallel_parser Parallel Parser
+ * @description Parses files across worker threads (comment parsing + tag extraction)
+ * @description Results are collected by file index, so their order never depends on thread scheduling
+ */
+
+import { Worker } from 'node:worker_threads';
+import os from 'node:os';
+import path from 'node:path';
+import { fileURLToPath } from 'node:url';
+import type { DocBlock } from '../types/index';
+import { getCustomPatterns, getDefaultLanguage } from '../parser/index';
+import { getCustomTags, getDocTagName, getDocumentPrivate, getTagPrefix } from '../extractor/index';
+import { addDiagnostics, reportFileParsed, type Diagnostic } from '../diagnostics/index';
+import type { CommentPatternConfig } from '../config/config-loader';
+import { getParseCacheDir } from './parse-cache';
+
+/**
+ * @doc ParseWorkerData ParseWorkerData
+ * @description Settings passed to each parser thread (module settings are not shared between threads)
+ */
+export interface ParseWorkerData {
+  workspaceRoot: string;
+  docTag: string;
+  tagPrefix: string;
+  customPatterns: Record<string, CommentPatternConfig> | null;
+  customTags: string[];
+  defaultLanguage: string | null;
+  documentPrivate: boolean;
+  cacheDir: string | null;
+}
+
+/**
+ * @doc ParseTask ParseTask
+ * @description File sent to a parser thread
+ */
+export interface ParseTask {
+  index: number;
+  filePath: string;
+}
+
+/**
+ * @doc ParseResult ParseResult
+ * @description Blocks parsed by a thread for one file, with the diagnostics it reported (a file that can't be parsed yields an error diagnostic and no blocks)
+ */
+export interface ParseResult {
+  index: number;
+  blocks: DocBlock[];
+  diagnostics: Diagnostic[];
+}
+
+// Below this number of files per thread, starting threads costs more than it saves
+const MIN_FILES_PER_THREAD = 32;
+
+// The worker has the extension of this module (.ts when run from sources, .js once built)
+const WORKER_URL = new URL(
+  `./parse-worker${path.extname(fileURLToPath(import.meta.url))}`,
+  import.meta.url
+);
+
+/**
+ * @doc getDefaultJobs getDefaultJobs
+ * @description Gets the default number of parser threads (number of logical CPUs)
+ * @returns The number of logical CPUs
+ */
+export function getDefaultJobs(): number {
+  return typeof os.availableParallelism === 'function' ? os.availableParallelism() : os.cpus().length;
+}
+
+/**
+ * @doc getThreadCount getThreadCount
+ * @description Gets the number of threads to use for a number of files, capped by jobs
+ * @param fileCount The number of files to parse
+ * @param jobs The maximum number of threads
+ * @returns The number of threads (1 means parsing on the main thread)
+ */
+export function getThreadCount(fileCount: number, jobs: number): number {
+  return Math.max(1, Math.min(jobs, Math.floor(fileCount / MIN_FILES_PER_THREAD)));
+}
+
+/**
+ * @doc parseFilesInThreads parseFilesInThreads
+ * @description Parses files on a pool of worker threads, each thread taking the next file when done
+ * @param filePaths Absolute paths of the files to parse
+ * @param workspaceRoot The workspace root directory for relative paths
+ * @param threadCount The number of threads to start
+ * @returns The blocks of all files, in file order
+ */
+export async function parseFilesInThreads(
+  filePaths: string[],
+  workspaceRoot: string,
+  threadCount: number
+): Promise<DocBlock[]> {
+  const workerData: ParseWorkerData = {
+    workspaceRoot,
+    docTag: getDocTagName(),
+    tagPrefix: getTagPrefix(),
+    customPatterns: getCustomPatterns(),
+    customTags: getCustomTags(),
+    defaultLanguage: getDefaultLanguage(),
+    documentPrivate: getDocumentPrivate(),
+    cacheDir: getParseCacheDir(),
+  };
+
+  const results: DocBlock[][] = new Array(filePaths.length).fill([]);
+  const diagnostics: Diagnostic[][] = new Array(filePaths.length).fill([]);
+  let next = 0;
+  let done = 0;
+  const takeNext = () => (next < filePaths.length ? next++ : -1);
+
+  // Files are reported in completion order: the counter follows the actual progress
+  const onParsed = (index: number) => {
+    done++;
+    reportFileParsed(path.relative(workspaceRoot, filePaths[index]), results[index].length, done, filePaths.length);
+  };
+
+  await Promise.all(
+    Array.from({ length: threadCount }, () => runWorker(workerData, filePaths, results, diagnostics, takeNext, onParsed))
+  );
+
+  addDiagnostics(diagnostics.flat());
+  return results.flat();
+}
+
+/**
+ * @doc runWorker runWorker
+ * @description Runs one parser thread until no file is left
+ * @param workerData The settings of the thread
+ * @param filePaths Absolute paths of all files
+ * @param results The blocks of each file, filled by index
+ * @param diagnostics The diagnostics of each file, filled by index
+ * @param takeNext Returns the index of the next file to parse, or -1 when all files are taken
+ * @param onParsed Called with the index of each parsed file
+ */
+function runWorker(
+  workerData: ParseWorkerData,
+  filePaths: string[],
+  results: DocBlock[][],
+  diagnostics: Diagnostic[][],
+  takeNext: () => number,
+  onParsed: (index: number) => void
+): Promise<void> {
+  return new Promise((resolve, reject) => {
+    const worker = new Worker(WORKER_URL, { workerData });
+
+    const sendNext = () => {
+      const index = takeNext();
+      if (index === -1) {
+        void worker.terminate();
+        resolve();
+        return;
+      }
+
+      const task: ParseTask = { index, filePath: filePaths[index] };
+      worker.postMessage(task);
+    };
+
+    worker.on('message', (result: ParseResult) => {
+      results[result.index] = result.blocks;
+      diagnostics[result.index] = result.diagnostics;
+      onParsed(result.index);
+      sendNext();
+    });
+    worker.on('error', reject);
+
+    sendNext();
+  });
+}
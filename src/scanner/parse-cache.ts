@@ -0,0 +1,167 @@
+/**
+ * @doc parse_cache Parse Cache
+ * @description Persistent cache of parsed files: unchanged files (same path, mtime and size) are loaded instead of re-parsed
+ * @description One JSON file per source file, holding its blocks and the diagnostics their parse reported
+ */
+
+import { createHash } from 'node:crypto';
+import { mkdirSync, readFileSync, statSync, writeFileSync } from 'node:fs';
+import path from 'node:path';
+import type { DocBlock } from '../types/index';
+import { getCustomPatterns, getDefaultLanguage } from '../parser/index';
+import { getCustomTags, getDocTagName, getDocumentPrivate, getTagPrefix } from '../extractor/index';
+import type { Diagnostic } from '../diagnostics/index';
+
+/**
+ * @doc PARSE_CACHE_VERSION PARSE_CACHE_VERSION
+ * @description Format version of cache files; bump it whenever the shape of DocBlock or DocItem changes, so older caches are ignored
+ */
+export const PARSE_CACHE_VERSION = 4;
+
+/**
+ * @doc ParseCacheEntry ParseCacheEntry
+ * @description Cached parse of a file; settings fingerprints the options the parse depends on (doc tag, tag prefix, patterns...)
+ */
+export interface ParseCacheEntry {
+  version: number;
+  settings: string;
+  filePath: string;
+  mtimeMs: number;
+  size: number;
+  blocks: DocBlock[];
+  diagnostics: Diagnostic[];
+}
+
+// Current cache directory (null: no cache, the CLI enables it)
+let PARSE_CACHE_DIR: string | null = null;
+
+/**
+ * @doc setParseCacheDir setParseCacheDir
+ * @description Sets the directory of the parse cache
+ * @param dir The cache directory, or null to always parse files (--no-cache)
+ */
+export function setParseCacheDir(dir: string | null): void {
+  PARSE_CACHE_DIR = dir;
+}
+
+/**
+ * @doc getParseCacheDir getParseCacheDir
+ * @description Gets the directory of the parse cache
+ * @returns The cache directory, or null if the cache is disabled
+ */
+export function getParseCacheDir(): string | null {
+  return PARSE_CACHE_DIR;
+}
+
+/**
+ * @doc withParseCache withParseCache
+ * @description Loads the blocks of a file from the cache when its mtime and size match, parses and caches them otherwise
+ * @description An unreadable or corrupted cache file is a cache miss; a cache that can't be written is ignored
+ * @param filePath Absolute path of the file
+ * @param workspaceRoot The workspace root directory (block paths are relative to it)
+ * @param parse Parses the file, returning its blocks and diagnostics
+ * @returns The blocks and diagnostics of the file, cached or parsed
+ */
+export function withParseCache(
+  filePath: string,
+  workspaceRoot: string,
+  parse: () => { blocks: DocBlock[]; diagnostics: Diagnostic[] }
+): { blocks: DocBlock[]; diagnostics: Diagnostic[] } {
+  const stats = PARSE_CACHE_DIR ? statFile(filePath) : null;
+  if (!PARSE_CACHE_DIR || !stats) {
+    return parse();
+  }
+
+  const cacheFile = getCacheFile(PARSE_CACHE_DIR, filePath);
+  const settings = getSettingsFingerprint(workspaceRoot);
+  const cached = readCacheEntry(cacheFile);
+  if (
+    cached &&
+    cached.version === PARSE_CACHE_VERSION &&
+    cached.settings === settings &&
+    cached.filePath === filePath &&
+    cached.mtimeMs === stats.mtimeMs &&
+    cached.size === stats.size
+  ) {
+    return { blocks: cached.blocks, diagnostics: cached.diagnostics };
+  }
+
+  const parsed = parse();
+  const entry: ParseCacheEntry = {
+    version: PARSE_CACHE_VERSION,
+    settings,
+    filePath,
+    mtimeMs: stats.mtimeMs,
+    size: stats.size,
+    ...parsed,
+  };
+  try {
+    mkdirSync(PARSE_CACHE_DIR, { recursive: true });
+    writeFileSync(cacheFile, JSON.stringify(entry), 'utf-8');
+  } catch {
+    // A read-only or missing cache only costs the next run a parse
+  }
+
+  return parsed;
+}
+
+/**
+ * @doc getCacheFile getCacheFile
+ * @description Gets the cache file of a source file, named after the hash of its absolute path
+ * @param cacheDir The cache directory
+ * @param filePath Absolute path of the source file
+ * @returns The cache file path
+ */
+export function getCacheFile(cacheDir: string, filePath: string): string {
+  return path.join(cacheDir, `${createHash('sha1').update(filePath).digest('hex')}.json`);
+}
+
+/**
+ * @doc readCacheEntry readCacheEntry
+ * @description Reads a cache file
+ * @param cacheFile The cache file path
+ * @returns The cache entry, or null if the file is missing, not JSON or not a cache entry
+ */
+function readCacheEntry(cacheFile: string): ParseCacheEntry | null {
+  try {
+    const entry = JSON.parse(readFileSync(cacheFile, 'utf-8')) as Partial<ParseCacheEntry> | null;
+    return entry && Array.isArray(entry.blocks) && Array.isArray(entry.diagnostics)
+      ? entry as ParseCacheEntry
+      : null;
+  } catch {
+    return null;
+  }
+}
+
+/**
+ * @doc statFile statFile
+ * @description Reads the modification time and size of a file
+ * @param filePath The file path
+ * @returns The mtime (ms) and size, or null if the file can't be read
+ */
+function statFile(filePath: string): { mtimeMs: number; size: number } | null {
+  try {
+    const stats = statSync(filePath);
+    return { mtimeMs: stats.mtimeMs, size: stats.size };
+  } catch {
+    return null;
+  }
+}
+
+/**
+ * @doc getSettingsFingerprint getSettingsFingerprint
+ * @description Fingerprints the settings a parse depends on, so changing one of them (e.g. --tag-prefix) invalidates the cache
+ * @param workspaceRoot The workspace root directory
+ * @returns The fingerprint
+ */
+function getSettingsFingerprint(workspaceRoot: string): string {
+  return JSON.stringify([
+    workspaceRoot,
+    getDocTagName(),
+    getTagPrefix(),
+    getCustomTags(),
+    getCustomPatterns(),
+    getDefaultLanguage(),
+    getDocumentPrivate(),
+  ]);
+}
@@ -0,0 +1,31 @@
+/**
+ * @doc parse_worker Parse Worker
+ * @description Worker thread parsing the files sent by the parallel parser
+ */
+
+import { parentPort, workerData } from 'node:worker_threads';
+import { setCustomPatterns, setDefaultLanguage } from '../parser/index';
+import { setCustomTags, setDocTagName, setDocumentPrivate, setTagPrefix } from '../extractor/index';
+import { captureDiagnostics } from '../diagnostics/index';
+import { scanFileOrReport } from './workspace-scanner';
+import { setParseCacheDir } from './parse-cache';
+import type { ParseResult, ParseTask, ParseWorkerData } from './parallel-parser';
+
+const settings = workerData as ParseWorkerData;
+setDocTagName(settings.docTag);
+setTagPrefix(settings.tagPrefix);
+setCustomPatterns(settings.customPatterns);
+setCustomTags(settings.customTags);
+setDefaultLanguage(settings.defaultLanguage);
+setDocumentPrivate(settings.documentPrivate);
+setParseCacheDir(settings.cacheDir);
+
+parentPort?.on('message', (task: ParseTask) => {
+  // Diagnostics are sent back to the main thread, which reports them in file order
+  const { result: blocks, diagnostics } = captureDiagnostics(
+    () => scanFileOrReport(task.filePath, settings.workspaceRoot)
+  );
+
+  const result: ParseResult = { index: task.index, blocks, diagnostics };
+  parentPort?.postMessage(result);
+});
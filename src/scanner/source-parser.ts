@@ -0,0 +1,77 @@
+/**
+ * @doc source_parser Source Parser
+ * @description Library entry point parsing a single source text into structured entries (for editor integrations)
+ */
+
+import type { DocEntry } from '../types/index';
+import { StandardocError } from '../types/index';
+import { LANGUAGE_FRONTENDS, getLanguage } from '../parser/index';
+import { buildDocEntries, generateCanonicalDoc } from '../generator/index';
+import { captureDiagnostics, reportError, type Diagnostic } from '../diagnostics/index';
+import { scanFile } from './workspace-scanner';
+
+/**
+ * @doc parseSource parseSource
+ * @description Parses source text and returns its documented entries
+ * @description Each entry carries its span (line and UTF-8 byte offset of the @doc comment)
+ * @param input The source text
+ * @param language The language name (e.g., 'rust', 'cpp') or a file extension (e.g., 'rs')
+ * @param filePath Optional file path reported in entry metadata (defaults to "input.<ext>")
+ * @returns The documented entries, in source order
+ * @throws StandardocError if the language is unknown or a @doc tag is invalid
+ */
+export function parseSource(input: string, language: string, filePath?: string): DocEntry[] {
+  const extension = getLanguageExtension(language);
+  if (!extension) {
+    throw new StandardocError(`Unsupported language: ${language}`);
+  }
+
+  const blocks = scanFile(filePath ?? `input.${extension}`, input, '.');
+  return buildDocEntries(generateCanonicalDoc(blocks));
+}
+
+/**
+ * @doc parseSourceWithDiagnostics parseSourceWithDiagnostics
+ * @description Parses source text like parseSource, returning the diagnostics as data instead of printing them
+ * @description An invalid source (unknown language, invalid @doc tag) yields an error diagnostic and no entries instead of throwing
+ * @param input The source text
+ * @param language The language name (e.g., 'rust', 'cpp') or a file extension (e.g., 'rs')
+ * @param filePath Optional file path reported in entry metadata and diagnostics (defaults to "input.<ext>")
+ * @returns The documented entries, in source order, and the diagnostics, in report order
+ */
+export function parseSourceWithDiagnostics(
+  input: string,
+  language: string,
+  filePath?: string
+): { entries: DocEntry[]; diagnostics: Diagnostic[] } {
+  const { result, diagnostics } = captureDiagnostics(() => {
+    try {
+      return parseSource(input, language, filePath);
+    } catch (error) {
+      if (error instanceof StandardocError) {
+        reportError(error.message, error.file, error.line, error.column);
+        return [];
+      }
+      throw error;
+    }
+  });
+
+  return { entries: result, diagnostics };
+}
+
+/**
+ * @doc getLanguageExtension getLanguageExtension
+ * @description Gets the file extension selecting the frontend of a language
+ * @param language The language name or a file extension
+ * @returns The extension without dot, or null if the language is unknown
+ */
+function getLanguageExtension(language: string): string | null {
+  const name = language.toLowerCase();
+  const frontend = LANGUAGE_FRONTENDS[name];
+  if (frontend) {
+    return frontend.extensions[0];
+  }
+
+  const extension = name.replace(/^\./, '');
+  return getLanguage(extension) ? extension : null;
+}
@@ -7,9 +7,23 @@ import { readFileSync } from 'node:fs';
 import fastGlob from 'fast-glob';
 import path from 'node:path';
 import type { ScannerConfig, CanonicalDoc, DocBlock } from '../types/index';
+import { StandardocError } from '../types/index';
 import { parseComments } from '../parser/index';
-import { extractDocBlocks } from '../extractor/index';
+import { extractDocBlocks, getDocumentPrivate } from '../extractor/index';
+import { isPublicVisibility } from '../analyzer/index';
 import { generateCanonicalDoc } from '../generator/index';
+import { validateReferences } from '../resolver/index';
+import {
+  addDiagnostics,
+  captureDiagnostics,
+  getDiagnostics,
+  reportError,
+  reportFileParsed,
+  reportScanSummary,
+  type Diagnostic,
+} from '../diagnostics/index';
+import { getDefaultJobs, getThreadCount, parseFilesInThreads } from './parallel-parser';
+import { getParseCacheDir, withParseCache } from './parse-cache';
 
 /**
  * @doc scanWorkspace scanWorkspace
@@ -19,36 +33,253 @@ import { generateCanonicalDoc } from '../generator/index';
  * @returns Canonical document with all extracted blocks
  */
 export async function scanWorkspace(config: ScannerConfig): Promise<CanonicalDoc> {
+  const allFiles = await findWorkspaceFiles(config);
+  return scanFilesParallel(allFiles, config.workspaceRoot, config.jobs);
+}
+
+/**
+ * @doc scanWorkspaceWithDiagnostics scanWorkspaceWithDiagnostics
+ * @description Scans a workspace like scanWorkspace, also returning the diagnostics reported by the scan
+ * @description Diagnostics are still printed unless printing is disabled (see setDiagnosticsPrinting)
+ * @param config Scanner configuration with workspace root, include/exclude patterns
+ * @returns Canonical document and the diagnostics of the scan, in file order
+ */
+export async function scanWorkspaceWithDiagnostics(
+  config: ScannerConfig
+): Promise<{ doc: CanonicalDoc; diagnostics: Diagnostic[] }> {
+  const before = getDiagnostics().length;
+  const doc = await scanWorkspace(config);
+  return { doc, diagnostics: getDiagnostics().slice(before) };
+}
+
+/**
+ * @doc scanFilesParallel scanFilesParallel
+ * @description Scans a list of files across parser threads and generates canonical JSON
+ * @description Small sets of files are scanned on the main thread; blocks are merged in file order either way
+ * @description Files are sorted by path first, so the output doesn't depend on the order they were found in
+ * @description Progress is reported on stderr as files are parsed (see setProgressMode)
+ * @param filePaths Absolute paths of the files to scan
+ * @param workspaceRoot The workspace root directory for relative paths
+ * @param jobs Maximum number of parser threads (default: number of logical CPUs)
+ * @returns Canonical document with all extracted blocks
+ */
+export async function scanFilesParallel(
+  filePaths: string[],
+  workspaceRoot: string,
+  jobs: number = getDefaultJobs()
+): Promise<CanonicalDoc> {
+  const startedAt = Date.now();
+  const sorted = [...filePaths].sort(compareFilePaths);
+  const threadCount = getThreadCount(sorted.length, jobs);
+  const doc = threadCount <= 1
+    ? scanFiles(sorted, workspaceRoot)
+    : buildWorkspaceDoc(await parseFilesInThreads(sorted, workspaceRoot, threadCount));
+
+  reportScanSummary(sorted.length, Object.keys(doc).length, Date.now() - startedAt);
+  return doc;
+}
+
+/**
+ * @doc compareFilePaths compareFilePaths
+ * @description Compares file paths by UTF-16 code units: a total order that doesn't depend on the locale or the platform
+ * @param a The first path
+ * @param b The second path
+ * @returns A negative number if a sorts first, 0 if equal, a positive number otherwise
+ */
+export function compareFilePaths(a: string, b: string): number {
+  const first = a.replace(/\\/g, '/');
+  const second = b.replace(/\\/g, '/');
+  return first < second ? -1 : first > second ? 1 : 0;
+}
+
+/**
+ * @doc scanFiles scanFiles
+ * @description Scans a list of files and generates canonical JSON
+ * @description Files removed before being read are skipped, files that can't be parsed are reported as errors
+ * @param filePaths Absolute paths of the files to scan
+ * @param workspaceRoot The workspace root directory for relative paths
+ * @returns Canonical document with all extracted blocks
+ */
+export function scanFiles(filePaths: string[], workspaceRoot: string): CanonicalDoc {
+  const allBlocks: DocBlock[] = [];
+
+  filePaths.forEach((filePath, index) => {
+    const blocks = scanFileOrReport(filePath, workspaceRoot);
+    reportFileParsed(path.relative(workspaceRoot, filePath), blocks.length, index + 1, filePaths.length);
+    allBlocks.push(...blocks);
+  });
+
+  return buildWorkspaceDoc(allBlocks);
+}
+
+/**
+ * @doc scanFileOrReport scanFileOrReport
+ * @description Reads and scans a file, reporting a parse failure as an error diagnostic instead of throwing
+ * @description With a parse cache (see setParseCacheDir), an unchanged file is loaded from the cache and its diagnostics are reported again
+ * @param filePath Absolute path of the file
+ * @param workspaceRoot The workspace root directory for relative paths
+ * @returns The blocks of the file (none if it was removed or can't be parsed)
+ */
+export function scanFileOrReport(filePath: string, workspaceRoot: string): DocBlock[] {
+  if (!getParseCacheDir()) {
+    return parseFileOrReport(filePath, workspaceRoot);
+  }
+
+  const { blocks, diagnostics } = withParseCache(filePath, workspaceRoot, () => {
+    const { result, diagnostics } = captureDiagnostics(() => parseFileOrReport(filePath, workspaceRoot));
+    return { blocks: result, diagnostics };
+  });
+  addDiagnostics(diagnostics);
+  return blocks;
+}
+
+/**
+ * @doc parseFileOrReport parseFileOrReport
+ * @description Reads and scans a file without the cache, reporting a parse failure as an error diagnostic
+ * @param filePath Absolute path of the file
+ * @param workspaceRoot The workspace root directory for relative paths
+ * @returns The blocks of the file (none if it was removed or can't be parsed)
+ */
+function parseFileOrReport(filePath: string, workspaceRoot: string): DocBlock[] {
+  try {
+    return scanFile(filePath, readFileSync(filePath, 'utf-8'), workspaceRoot);
+  } catch (error) {
+    if (error instanceof Error && 'code' in error && error.code === 'ENOENT') {
+      return [];
+    }
+
+    const relativePath = path.relative(workspaceRoot, filePath);
+    if (error instanceof StandardocError) {
+      reportError(error.message, relativePath, error.line, error.column);
+    } else {
+      reportError(`could not parse file: ${error instanceof Error ? error.message : String(error)}`, relativePath);
+    }
+    return [];
+  }
+}
+
+/**
+ * @doc buildWorkspaceDoc buildWorkspaceDoc
+ * @description Merges the blocks of all files into the canonical document, then validates cross-references
+ * @description Runs on a single thread once all files are parsed, so the key table is built in file order
+ * @description Blocks of private items (not pub nor pub(crate)) are left out unless private items are documented (--document-private)
+ * @param blocks The blocks of all files, in file order
+ * @returns Canonical document with the documented blocks
+ */
+function buildWorkspaceDoc(blocks: DocBlock[]): CanonicalDoc {
+  const documented = getDocumentPrivate()
+    ? blocks
+    : blocks.filter(block => isPublicVisibility(block.item?.visibility));
+  const doc = generateCanonicalDoc(documented);
+  validateReferences(doc);
+
+  return doc;
+}
+
+/**
+ * @doc DEFAULT_EXCLUDE_PATTERNS DEFAULT_EXCLUDE_PATTERNS
+ * @description Directories always skipped: dependencies, build output and hidden directories (.git...)
+ */
+export const DEFAULT_EXCLUDE_PATTERNS = [
+  '**/node_modules/**',
+  '**/dist/**',
+  '**/build/**',
+  '**/target/**',
+  '**/.*/**',
+];
+
+/**
+ * @doc findWorkspaceFiles findWorkspaceFiles
+ * @description Finds the files of a workspace matching include/exclude patterns
+ * @description Patterns are relative to the workspace root; excludes are applied after includes, on top of the default excludes
+ * @param config Scanner configuration with workspace root, include/exclude patterns
+ * @returns Absolute paths of the matching files, sorted (see compareFilePaths)
+ * @throws StandardocError if a pattern is not a valid glob
+ */
+export async function findWorkspaceFiles(config: ScannerConfig): Promise<string[]> {
   const {
     workspaceRoot,
     includePatterns = ['**/*'],
-    excludePatterns = ['**/node_modules/**', '**/.git/**', '**/dist/**', '**/build/**'],
+    excludePatterns = [],
   } = config;
 
-  const allFiles = await fastGlob(includePatterns, {
+  validateGlobPatterns([...includePatterns, ...excludePatterns]);
+
+  const files = await fastGlob(includePatterns, {
     cwd: workspaceRoot,
-    ignore: excludePatterns,
+    ignore: [...DEFAULT_EXCLUDE_PATTERNS, ...excludePatterns],
     absolute: true,
   });
 
-  const allBlocks: DocBlock[] = [];
+  return files.sort(compareFilePaths);
+}
 
-  for (const filePath of allFiles) {
-    try {
-      const content = readFileSync(filePath, 'utf-8');
-      const extension = path.extname(filePath);
+/**
+ * @doc isExcludedFile isExcludedFile
+ * @description Checks whether an existing file is skipped by the default excludes or the exclude patterns, with the same matcher as findWorkspaceFiles
+ * @param config Scanner configuration with workspace root and exclude patterns
+ * @param relativePath The file path, relative to the workspace root
+ * @returns True if findWorkspaceFiles never returns the file (also when it doesn't exist or isn't a file)
+ */
+export async function isExcludedFile(config: ScannerConfig, relativePath: string): Promise<boolean> {
+  const { workspaceRoot, excludePatterns = [] } = config;
+  const files = await fastGlob([fastGlob.escapePath(relativePath.replace(/\\/g, '/'))], {
+    cwd: workspaceRoot,
+    ignore: [...DEFAULT_EXCLUDE_PATTERNS, ...excludePatterns],
+  });
+  return files.length === 0;
+}
 
-      const comments = parseComments(content, filePath, { extension });
-      const blocks = extractDocBlocks(comments, filePath, workspaceRoot);
+/**
+ * @doc validateGlobPatterns validateGlobPatterns
+ * @description Checks that glob patterns are well-formed, so a typo fails loudly instead of matching nothing
+ * @description Rejects empty patterns and unbalanced [], {} or () groups
+ * @param patterns The glob patterns to check
+ * @throws StandardocError on the first invalid pattern
+ */
+export function validateGlobPatterns(patterns: string[]): void {
+  const closing: Record<string, string> = { '[': ']', '{': '}', '(': ')' };
+
+  for (const pattern of patterns) {
+    if (pattern.trim() === '') {
+      throw new StandardocError('Invalid glob pattern: empty pattern');
+    }
 
-      allBlocks.push(...blocks);
-    } catch (error) {
-      if (error instanceof Error && 'code' in error && error.code === 'ENOENT') {
-        continue;
+    const stack: string[] = [];
+    for (let i = 0; i < pattern.length; i++) {
+      const char = pattern[i];
+      if (char === '\\') {
+        i++;
+      } else if (stack[stack.length - 1] === '[') {
+        // Inside a character class, only the closing bracket is special
+        if (char === ']') {
+          stack.pop();
+        }
+      } else if (char in closing) {
+        stack.push(char);
+      } else if (char === ']' || char === '}' || char === ')') {
+        if (closing[stack.pop() ?? ''] !== char) {
+          throw new StandardocError(`Invalid glob pattern '${pattern}': unexpected '${char}' at position ${i + 1}`);
+        }
       }
-      console.warn(`Error parsing ${filePath}:`, error);
+    }
+
+    if (stack.length > 0) {
+      throw new StandardocError(`Invalid glob pattern '${pattern}': unclosed '${stack[stack.length - 1]}'`);
     }
   }
+}
 
-  return generateCanonicalDoc(allBlocks);
+/**
+ * @doc scanFile scanFile
+ * @description Parses the comments of a single file and extracts its documentable blocks
+ * @param filePath The absolute file path
+ * @param content The file content
+ * @param workspaceRoot The workspace root directory for relative paths
+ * @returns The documentable blocks found in the file
+ */
+export function scanFile(filePath: string, content: string, workspaceRoot: string): DocBlock[] {
+  const extension = path.extname(filePath);
+  const comments = parseComments(content, filePath, { extension });
+  return extractDocBlocks(comments, filePath, workspaceRoot, content);
 }
@@ -0,0 +1,174 @@
+/**
+ * @doc example_tester Example Tester
+ * @description Extracts the rust code blocks of @example tags and checks that they compile with rustc
+ * @description Examples are only compiled (--emit=metadata), never run
+ */
+
+import { spawnSync } from 'node:child_process';
+import { mkdtempSync, rmSync, writeFileSync } from 'node:fs';
+import os from 'node:os';
+import path from 'node:path';
+import type { CanonicalDoc } from '../types/index';
+import { StandardocError } from '../types/index';
+import { getTagData } from '../generator/index';
+
+/**
+ * @doc ExampleSnippet ExampleSnippet
+ * @description Rust code block found in an @example tag
+ * @description index is the 1-based position of the code block among the examples of the entry
+ */
+export interface ExampleSnippet {
+  id: string;
+  file: string;
+  line: number;
+  index: number;
+  code: string;
+}
+
+/**
+ * @doc ExampleResult ExampleResult
+ * @description Outcome of compiling an example (output holds the rustc diagnostics of a failure)
+ */
+export interface ExampleResult {
+  snippet: ExampleSnippet;
+  passed: boolean;
+  output: string;
+}
+
+/**
+ * @doc ExampleTestOptions ExampleTestOptions
+ * @description Options of the test command (rustc binary, default: rustc from the PATH)
+ */
+export interface ExampleTestOptions {
+  rustc?: string;
+}
+
+// Fenced code blocks of an example: language tag and code
+const CODE_BLOCK_REGEX = /^(```|~~~)([^\n]*)\n([\s\S]*?)^\1[ \t]*$/gm;
+
+// Fence attributes of rust code blocks that must not be compiled
+const SKIPPED_ATTRIBUTES = ['ignore', 'compile_fail'];
+
+/**
+ * @doc extractRustExamples extractRustExamples
+ * @description Extracts the rust code blocks of every @example tag, in document order
+ * @description A block is rust when its fence says so (```rust), or when its fence has no language (or the example no fence) and the tag does (@example rust)
+ * @description Other blocks (```c, @example js, unmarked examples of .rs files...) and rust blocks marked ignore or compile_fail are skipped
+ * @param doc The canonical document containing all blocks
+ * @returns The rust snippets with their originating entry
+ */
+export function extractRustExamples(doc: CanonicalDoc): ExampleSnippet[] {
+  const snippets: ExampleSnippet[] = [];
+
+  for (const [docKey, block] of Object.entries(doc)) {
+    let index = 0;
+
+    for (const [content = '', , language = ''] of getTagData(block, 'example')) {
+      const matches = [...content.matchAll(CODE_BLOCK_REGEX)];
+      const codeBlocks = matches.length > 0
+        ? matches.map(match => ({ attributes: match[2].trim().split(/[\s,]+/).filter(Boolean), code: match[3] }))
+        : content.trim() ? [{ attributes: [] as string[], code: content }] : [];
+
+      for (const { attributes, code } of codeBlocks) {
+        index++;
+        const blockLanguage = attributes[0] ?? language;
+        if (blockLanguage !== 'rust' || attributes.some(attribute => SKIPPED_ATTRIBUTES.includes(attribute))) {
+          continue;
+        }
+
+        snippets.push({
+          id: docKey.slice('doc.'.length),
+          file: block.meta.path,
+          line: block.meta.line,
+          index,
+          code,
+        });
+      }
+    }
+  }
+
+  return snippets;
+}
+
+/**
+ * @doc wrapExample wrapExample
+ * @description Wraps an example in a fn main harness, unless it already declares one
+ * @description Hidden lines ("# code", as in rustdoc) are compiled without their marker
+ * @param code The example code
+ * @returns The compilable source
+ */
+export function wrapExample(code: string): string {
+  const source = code
+    .split('\n')
+    .map(line => line.replace(/^(\s*)#(?: |$)/, '$1'))
+    .join('\n');
+
+  if (/\bfn\s+main\s*\(/.test(source)) {
+    return source;
+  }
+
+  // Inner attributes (#![...]) must stay at the crate root
+  const lines = source.split('\n');
+  const attributes: string[] = [];
+  while (lines.length > 0 && /^\s*#!\[.*\]\s*$/.test(lines[0])) {
+    attributes.push(lines.shift()!);
+  }
+
+  return [
+    '#![allow(unused)]',
+    ...attributes,
+    'fn main() {',
+    ...lines.map(line => (line ? `    ${line}` : line)),
+    '}',
+    '',
+  ].join('\n');
+}
+
+/**
+ * @doc testExamples testExamples
+ * @description Compiles each snippet with rustc --edition 2021 --emit=metadata in a temporary directory
+ * @param snippets The snippets to compile
+ * @param options Test options (rustc binary)
+ * @returns One result per snippet, in the same order
+ * @throws StandardocError if rustc can't be started
+ */
+export function testExamples(snippets: ExampleSnippet[], options: ExampleTestOptions = {}): ExampleResult[] {
+  const { rustc = 'rustc' } = options;
+  const dir = mkdtempSync(path.join(os.tmpdir(), 'standardoc-test-'));
+
+  try {
+    return snippets.map((snippet, i) => {
+      const filePath = path.join(dir, `example_${i + 1}.rs`);
+      writeFileSync(filePath, wrapExample(snippet.code), 'utf-8');
+
+      const result = spawnSync(
+        rustc,
+        ['--edition', '2021', '--emit=metadata', '--crate-type', 'bin', '--crate-name', `example_${i + 1}`, '--out-dir', dir, filePath],
+        { encoding: 'utf-8' }
+      );
+
+      if (result.error) {
+        throw new StandardocError(`Could not run ${rustc}: ${result.error.message}`);
+      }
+
+      return {
+        snippet,
+        passed: result.status === 0,
+        output: `${result.stdout}${result.stderr}`.trim(),
+      };
+    });
+  } finally {
+    rmSync(dir, { recursive: true, force: true });
+  }
+}
+
+/**
+ * @doc formatExampleResult formatExampleResult
+ * @description Formats a result as "PASS|FAIL id (path:line, example n)"
+ * @param result The example result
+ * @returns The formatted result
+ */
+export function formatExampleResult(result: ExampleResult): string {
+  const { snippet } = result;
+  return `${result.passed ? 'PASS' : 'FAIL'} ${snippet.id} (${snippet.file}:${snippet.line}, example ${snippet.index})`;
+}
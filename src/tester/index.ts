@@ -0,0 +1,6 @@
+/**
+ * @doc tester Tester Module
+ * @description Compiles the code blocks of @example tags (doctests)
+ */
+
+export * from './example-tester';
@@ -6,13 +6,18 @@
 /**
  * @doc DocMeta DocMeta
  * @description Automatically injected metadata for each documentable block
+ * @description Offset is the UTF-8 byte offset of the @doc comment in the file
+ * @description Key is the explicit @doc key; generatedKey marks blocks whose key is derived from the item name
  */
 export interface DocMeta {
   path: string;
   line: number;
+  offset: number;
   file: string;
   ext: string;
   lastEdit: string;
+  key?: string;
+  generatedKey?: boolean;
 }
 
 /**
@@ -22,6 +27,98 @@ export interface DocMeta {
  */
 export type TagData = string[][];
 
+/**
+ * @doc ItemKind ItemKind
+ * @description Kind of source item following a doc block
+ */
+export type ItemKind =
+  | 'function'
+  | 'method'
+  | 'struct'
+  | 'enum'
+  | 'trait'
+  | 'impl'
+  | 'class'
+  | 'interface'
+  | 'module'
+  | 'type'
+  | 'const';
+
+/**
+ * @doc SignatureParam SignatureParam
+ * @description Parameter parsed from a function signature (type is empty if not annotated)
+ */
+export interface SignatureParam {
+  name: string;
+  type: string;
+}
+
+/**
+ * @doc ItemVariant ItemVariant
+ * @description Variant parsed from an enum body; fields are the tuple types or "name: Type" struct fields (empty for unit variants)
+ */
+export interface ItemVariant {
+  name: string;
+  fields: string[];
+}
+
+/**
+ * @doc ItemField ItemField
+ * @description Named field parsed from a Rust struct body; public is true for pub and pub(...) fields
+ */
+export interface ItemField {
+  name: string;
+  type: string;
+  public: boolean;
+}
+
+/**
+ * @doc ItemMethod ItemMethod
+ * @description Method declared in a Rust trait body (required or with a default body), with its signature
+ */
+export interface ItemMethod {
+  name: string;
+  signature: string;
+}
+
+/**
+ * @doc MethodReceiver MethodReceiver
+ * @description Receiver of a Rust method: borrowed (&self), mutably borrowed (&mut self), consumed (self), or none for associated functions (static)
+ */
+export type MethodReceiver = 'self' | '&self' | '&mut self' | 'static';
+
+/**
+ * @doc DocItem DocItem
+ * @description Source item detected right after a doc block
+ * @description Parent is the name of the enclosing type (impl, class, trait...) if any
+ * @description For functions, returnType is null when nothing is returned and absent when unknown
+ * @description Generics lists the declared type parameters, absent when the language has none
+ * @description For enums, variants lists the variants in declaration order; for Rust structs, fields lists the named fields
+ * @description cfg is the condition of the #[cfg(...)] attributes of a Rust item (several are combined with all(...))
+ * @description visibility is the qualifier of a Rust item (pub, pub(crate), pub(super)... or private), absent when not parsed (other languages, trait items)
+ * @description For Rust traits, methods lists the declared methods; for trait impls (impl Trait for Type), trait is the name of the implemented trait
+ * @description modules lists the inline modules (mod name { ... }) enclosing the item, outermost first, absent at file level
+ * @description For Rust methods, receiver is how the method takes self (static for associated functions such as constructors)
+ */
+export interface DocItem {
+  kind: ItemKind;
+  name: string;
+  line: number;
+  signature: string;
+  parent?: string;
+  params?: SignatureParam[];
+  returnType?: string | null;
+  generics?: string[];
+  variants?: ItemVariant[];
+  fields?: ItemField[];
+  cfg?: string;
+  visibility?: string;
+  methods?: ItemMethod[];
+  trait?: string;
+  modules?: string[];
+  receiver?: MethodReceiver;
+}
+
 /**
  * @doc DocBlock DocBlock
  * @description Canonical structure of a documentable block
@@ -29,7 +126,8 @@ export type TagData = string[][];
 export interface DocBlock {
   label: string;
   meta: DocMeta;
-  [tag: string]: string | DocMeta | TagData;
+  item?: DocItem;
+  [tag: string]: string | DocMeta | TagData | DocItem | undefined;
 }
 
 /**
@@ -41,15 +139,175 @@ export interface CanonicalDoc {
   [key: `doc.${string}`]: DocBlock;
 }
 
+/**
+ * @doc DocParam DocParam
+ * @description Parameter of a documented entry
+ * @description default is the literal value of the @default tag following the @param, absent without one
+ */
+export interface DocParam {
+  name: string;
+  type: string;
+  description: string;
+  default?: string;
+}
+
+/**
+ * @doc DocGeneric DocGeneric
+ * @description Type parameter of a documented entry (@generic name description)
+ */
+export interface DocGeneric {
+  name: string;
+  description: string;
+}
+
+/**
+ * @doc DocVariant DocVariant
+ * @description Enum variant of a documented entry (@variant name description), with the field types parsed from the source
+ */
+export interface DocVariant {
+  name: string;
+  fields: string[];
+  description: string;
+}
+
+/**
+ * @doc DocField DocField
+ * @description Struct field documented by a @field tag
+ * @description default is the literal value of the @default tag following the @field, absent without one
+ */
+export interface DocField {
+  name: string;
+  type: string;
+  description: string;
+  default?: string;
+}
+
+/**
+ * @doc DocReturn DocReturn
+ * @description Return value of a documented entry
+ */
+export interface DocReturn {
+  type: string;
+  description: string;
+}
+
+/**
+ * @doc DocExample DocExample
+ * @description Example attached to a documented entry
+ * @description Content is kept verbatim, including code fences (empty when the tag has no code)
+ * @description title is the text following @example on the tag line, null when absent
+ * @description language is the info string of the code fence, else the language given on the tag line (@example c), else the source language of the file; null when none is known
+ */
+export interface DocExample {
+  title: string | null;
+  content: string;
+  language: string | null;
+}
+
+/**
+ * @doc DocDeprecation DocDeprecation
+ * @description Deprecation notice of a documented entry (@deprecated [version] [message])
+ */
+export interface DocDeprecation {
+  since: string | null;
+  message: string;
+}
+
+/**
+ * @doc DocSpan DocSpan
+ * @description Source position of a documented entry (1-based line, UTF-8 byte offset)
+ * @description itemLine is the line of the item definition following the doc comment, null when no item was detected
+ */
+export interface DocSpan {
+  line: number;
+  offset: number;
+  itemLine: number | null;
+}
+
+/**
+ * @doc DocEntry DocEntry
+ * @description Structured representation of a documentable block (AST output)
+ * @description kind is the detected item kind ('note' for a free-standing block followed by no item) and module its module path, so free functions can be grouped by module
+ * @description signature is the normalized declaration of the item, null without item
+ * @description errors and panics list the conditions of the @errors and @panics tags
+ * @description variants lists every enum variant, undocumented ones with an empty description; fields lists the @field tags of a struct
+ * @description since is the version of the @since tag, category the name of the @category tag and group the name of the @group tag (methods), null when absent
+ * @description cfg is the #[cfg(...)] condition of the item, null when the item is not conditionally compiled
+ * @description private is true for items that are only documented with --document-private (not pub nor pub(crate))
+ * @description methods lists every method declared by a trait, documented or not; implements is the trait of an impl Trait for Type block, null otherwise
+ * @description internal is true for entries hidden from rendered output unless --show-internal is set (@internal tag, or a member of an @internal type)
+ * @description receiver is the receiver of a Rust method (&self, &mut self, self or static), null for other entries
+ * @description aliases lists the @alias names under which references and search also find the entry
+ * @description summary is the first sentence of the description (see getSummary), for index pages and tooltips
+ */
+export interface DocEntry {
+  id: string;
+  name: string;
+  kind: ItemKind | 'note';
+  module: string;
+  signature: string | null;
+  description: string;
+  summary: string;
+  generics: DocGeneric[];
+  params: DocParam[];
+  variants: DocVariant[];
+  fields: DocField[];
+  methods: ItemMethod[];
+  implements: string | null;
+  receiver: MethodReceiver | null;
+  aliases: string[];
+  returns: DocReturn | null;
+  errors: string[];
+  panics: string[];
+  examples: DocExample[];
+  deprecated: DocDeprecation | null;
+  since: string | null;
+  category: string | null;
+  group: string | null;
+  cfg: string | null;
+  private: boolean;
+  internal: boolean;
+  see: string[];
+  span: DocSpan;
+}
+
+/**
+ * @doc OutputFormat OutputFormat
+ * @description Supported output formats for the scan command
+ */
+export type OutputFormat = 'canonical' | 'json' | 'html' | 'markdown';
+
+/**
+ * @doc GeneratedFile GeneratedFile
+ * @description File produced by a page-based generator (name relative to the output directory)
+ */
+export interface GeneratedFile {
+  fileName: string;
+  content: string;
+}
+
+/**
+ * @doc CheckProblem CheckProblem
+ * @description Problem reported by the check command for a doc block or source item
+ */
+export interface CheckProblem {
+  severity: 'error' | 'warning';
+  message: string;
+  file: string;
+  line: number;
+}
+
 /**
  * @doc ScannerConfig ScannerConfig
  * @description Configuration for workspace scanner
+ * @description jobs caps the number of parser threads (default: number of logical CPUs)
  */
 export interface ScannerConfig {
   includePatterns?: string[];
   excludePatterns?: string[];
   outputPath?: string;
   workspaceRoot: string;
+  jobs?: number;
 }
 
 /**
@@ -74,11 +332,15 @@ export type CommentStyle =
 /**
  * @doc ParsedComment ParsedComment
  * @description Result of parsing a comment from source code
+ * @description Offset is the UTF-8 byte offset of the comment prefix
+ * @description contentLine is the source line of the first content line (after an opening line like "/**")
  */
 export interface ParsedComment {
   raw: string;
   line: number;
+  contentLine: number;
   column: number;
+  offset: number;
   style: CommentStyle;
   content: string;
 }
@@ -86,12 +348,14 @@ export interface ParsedComment {
 /**
  * @doc ExtractedTag ExtractedTag
  * @description Result of extracting a tag from a comment
+ * @description line and column locate the tag prefix in the source (1-based)
  */
 export interface ExtractedTag {
   name: string;
   args: string[];
   content: string;
   line: number;
+  column: number;
 }
 
 /**
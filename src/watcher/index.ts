@@ -0,0 +1,6 @@
+/**
+ * @doc watcher Watcher Module
+ * @description Watch mode regenerating the output on file changes
+ */
+
+export * from './workspace-watcher';
@@ -0,0 +1,250 @@
+/**
+ * @doc workspace_watcher Workspace Watcher
+ * @description Regenerates the output whenever a source file of the workspace changes
+ */
+
+import { existsSync, watch, type FSWatcher } from 'node:fs';
+import { rm } from 'node:fs/promises';
+import path from 'node:path';
+import type { CanonicalDoc, GeneratedFile, OutputFormat, ScannerConfig } from '../types/index';
+import { findWorkspaceFiles, isExcludedFile, scanFilesParallel } from '../scanner/index';
+import {
+  buildDocEntries,
+  generateHtmlSingleFile,
+  generateHtmlSite,
+  generateMarkdownBook,
+  generateMarkdownSingleFile,
+  serializeCanonicalDoc,
+  serializeDocEntries,
+  writeFileIfChanged,
+} from '../generator/index';
+import { filterByMinVersion, type ParsedVersion } from '../resolver/index';
+import { getWarningCount, resetDiagnostics } from '../diagnostics/index';
+
+/**
+ * @doc WatchOptions WatchOptions
+ * @description Options of the watch mode
+ * @description Events received within debounceMs of each other trigger a single rebuild
+ * @description minVersion drops entries whose @since is older (see filterByMinVersion)
+ * @description singleFile writes html and markdown output as one document at outputPath
+ */
+export interface WatchOptions {
+  format: OutputFormat;
+  outputPath: string;
+  minVersion?: ParsedVersion;
+  singleFile?: boolean;
+  debounceMs?: number;
+  onBuild?: (summary: BuildSummary) => void;
+  onError?: (error: unknown) => void;
+}
+
+/**
+ * @doc BuildSummary BuildSummary
+ * @description Summary of a generation (files parsed, entries found, output files written or removed, warnings, elapsed time)
+ */
+export interface BuildSummary {
+  files: number;
+  entries: number;
+  written: number;
+  warnings: number;
+  elapsedMs: number;
+}
+
+/**
+ * @doc watchWorkspace watchWorkspace
+ * @description Runs an initial generation, then regenerates the output on each change of the workspace
+ * @description Bursts of filesystem events are debounced (200ms by default); deleted files drop out of the next generation
+ * @description Changes the scanner would never read (generated output, default excludes such as target/ or .git/, exclude patterns) are ignored
+ * @param config Scanner configuration with workspace root, include/exclude patterns
+ * @param options Watch options (format, output path, debounce window, callbacks)
+ * @returns The filesystem watcher (call close() to stop watching)
+ */
+export async function watchWorkspace(config: ScannerConfig, options: WatchOptions): Promise<FSWatcher> {
+  const { debounceMs = 200, onBuild, onError } = options;
+  const previousFiles = new Set<string>();
+  const inputFiles = new Set<string>();
+
+  let timer: ReturnType<typeof setTimeout> | null = null;
+  let building = false;
+  let pending = false;
+
+  const rebuild = async () => {
+    if (building) {
+      pending = true;
+      return;
+    }
+
+    building = true;
+    try {
+      onBuild?.(await buildWorkspace(config, options, previousFiles, inputFiles));
+    } catch (error) {
+      onError?.(error);
+    } finally {
+      building = false;
+    }
+
+    if (pending) {
+      pending = false;
+      await rebuild();
+    }
+  };
+
+  await rebuild();
+
+  const schedule = () => {
+    if (timer) {
+      clearTimeout(timer);
+    }
+    timer = setTimeout(() => {
+      timer = null;
+      void rebuild();
+    }, debounceMs);
+  };
+
+  return watch(config.workspaceRoot, { recursive: true }, (_event, fileName) => {
+    if (!fileName) {
+      schedule();
+      return;
+    }
+
+    isIgnoredChange(config, options.outputPath, fileName.toString(), inputFiles).then(
+      ignored => {
+        if (!ignored) {
+          schedule();
+        }
+      },
+      error => onError?.(error)
+    );
+  });
+}
+
+/**
+ * @doc buildWorkspace buildWorkspace
+ * @description Scans the workspace and writes the output files whose content changed
+ * @description Output files of the previous generation that are no longer produced are removed
+ * @param config Scanner configuration with workspace root, include/exclude patterns
+ * @param options Watch options (format, output path, minimum version and single-file mode)
+ * @param previousFiles Output files written by the previous generation (updated in place)
+ * @param inputFiles Source files scanned by the previous generation, absolute (updated in place)
+ * @returns The generation summary
+ */
+export async function buildWorkspace(
+  config: ScannerConfig,
+  options: Pick<WatchOptions, 'format' | 'outputPath' | 'minVersion' | 'singleFile'>,
+  previousFiles: Set<string> = new Set(),
+  inputFiles: Set<string> = new Set()
+): Promise<BuildSummary> {
+  const start = performance.now();
+  resetDiagnostics();
+
+  const files = await findWorkspaceFiles(config);
+  inputFiles.clear();
+  for (const file of files) {
+    inputFiles.add(path.resolve(file));
+  }
+  const scanned = await scanFilesParallel(files, config.workspaceRoot, config.jobs);
+  const doc = options.minVersion ? filterByMinVersion(scanned, options.minVersion) : scanned;
+  const outputFiles = renderOutputFiles(doc, options.format, options.outputPath, options.singleFile);
+
+  let written = 0;
+  for (const file of outputFiles) {
+    if (await writeFileIfChanged(file.fileName, file.content)) {
+      written++;
+    }
+  }
+
+  const currentFiles = new Set(outputFiles.map(file => file.fileName));
+  for (const stale of previousFiles) {
+    if (!currentFiles.has(stale)) {
+      await rm(stale, { force: true });
+      written++;
+    }
+  }
+
+  previousFiles.clear();
+  for (const file of currentFiles) {
+    previousFiles.add(file);
+  }
+
+  return {
+    files: files.length,
+    entries: Object.keys(doc).length,
+    written,
+    warnings: getWarningCount(),
+    elapsedMs: Math.round(performance.now() - start),
+  };
+}
+
+/**
+ * @doc renderOutputFiles renderOutputFiles
+ * @description Renders the output files of a document for a format
+ * @param doc The canonical document
+ * @param format The output format
+ * @param outputPath The output file (canonical, json, single file) or directory (html, markdown)
+ * @param singleFile Whether html and markdown output is a single document
+ * @returns The output files with absolute file names
+ */
+function renderOutputFiles(
+  doc: CanonicalDoc,
+  format: OutputFormat,
+  outputPath: string,
+  singleFile = false
+): GeneratedFile[] {
+  if (singleFile && (format === 'html' || format === 'markdown')) {
+    const content = format === 'html' ? generateHtmlSingleFile(doc) : generateMarkdownSingleFile(doc);
+    return [{ fileName: outputPath, content }];
+  }
+
+  if (format === 'html' || format === 'markdown') {
+    const files = format === 'html' ? generateHtmlSite(doc) : generateMarkdownBook(doc);
+    return files.map(file => ({
+      fileName: path.join(outputPath, file.fileName),
+      content: file.content,
+    }));
+  }
+
+  const content = format === 'json'
+    ? serializeDocEntries(buildDocEntries(doc))
+    : serializeCanonicalDoc(doc);
+  return [{ fileName: outputPath, content }];
+}
+
+/**
+ * @doc isIgnoredChange isIgnoredChange
+ * @description Checks whether a changed path is generated output or a path the scanner never reads
+ * @description An existing path is matched like findWorkspaceFiles does (see isExcludedFile); a removed one only matters if a scanned file was at or under it
+ * @param config Scanner configuration with workspace root and exclude patterns
+ * @param outputPath The output file or directory
+ * @param fileName The changed path, relative to the workspace root
+ * @param inputFiles Source files scanned by the last generation, absolute
+ * @returns True if the change must not trigger a rebuild
+ */
+async function isIgnoredChange(
+  config: ScannerConfig,
+  outputPath: string,
+  fileName: string,
+  inputFiles: Set<string>
+): Promise<boolean> {
+  const changedPath = path.resolve(config.workspaceRoot, fileName);
+  if (isInside(path.resolve(outputPath), changedPath)) {
+    return true;
+  }
+
+  if (!existsSync(changedPath)) {
+    return ![...inputFiles].some(file => isInside(changedPath, file));
+  }
+
+  return isExcludedFile(config, fileName);
+}
+
+/**
+ * @doc isInside isInside
+ * @description Checks whether a path is another one or lies under it
+ * @param parent The parent path, absolute
+ * @param child The child path, absolute
+ * @returns True if child is parent or is under it
+ */
+function isInside(parent: string, child: string): boolean {
+  const relative = path.relative(parent, child);
+  return relative === '' || (!relative.startsWith('..') && !path.isAbsolute(relative));
+}
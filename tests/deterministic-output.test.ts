@@ -0,0 +1,85 @@
+import { afterAll, describe, expect, test } from 'bun:test';
+import { mkdirSync, mkdtempSync, rmSync, writeFileSync } from 'node:fs';
+import os from 'node:os';
+import path from 'node:path';
+import { generateHtmlSite, generateMarkdownBook, serializeCanonicalDoc } from '../src/generator/index';
+import { compareFilePaths, scanFilesParallel, scanWorkspace } from '../src/scanner/index';
+
+const root = mkdtempSync(path.join(os.tmpdir(), 'standardoc-deterministic-'));
+
+// Files are written out of order, so a walk in creation order differs from the sorted order
+const files: Record<string, string> = {
+  'src/z_last.rs': [
+    '/// @doc',
+    '/// @description Last function',
+    '/// @see first',
+    'pub fn last() {}',
+  ].join('\n'),
+  'src/math/vector.rs': [
+    '/// @doc vector Vector',
+    '/// @description A vector',
+    '/// @category Geometry',
+    'pub struct Vector {}',
+    '',
+    'impl Vector {',
+    '    /// @doc',
+    '    /// @description Length of the vector',
+    '    pub fn length(&self) -> f64 { 0.0 }',
+    '}',
+  ].join('\n'),
+  'src/a_first.rs': [
+    '/// @doc first first',
+    '/// @description First function',
+    'pub fn first() {}',
+    '',
+    '/// @doc',
+    '/// @description Shares its name with a function of another module',
+    '/// @category Geometry',
+    'pub fn length() {}',
+  ].join('\n'),
+  'src/math/angle.rs': [
+    '/// @doc',
+    '/// @description An angle',
+    'pub fn normalize(angle: f64) -> f64 { angle }',
+  ].join('\n'),
+};
+
+for (const [file, content] of Object.entries(files)) {
+  mkdirSync(path.dirname(path.join(root, file)), { recursive: true });
+  writeFileSync(path.join(root, file), content, 'utf-8');
+}
+
+afterAll(() => rmSync(root, { recursive: true, force: true }));
+
+describe('deterministic output', () => {
+  test('scanning the same tree twice gives byte-identical output', async () => {
+    const first = await scanWorkspace({ workspaceRoot: root });
+    const second = await scanWorkspace({ workspaceRoot: root });
+
+    expect(serializeCanonicalDoc(second)).toBe(serializeCanonicalDoc(first));
+    expect(generateHtmlSite(second)).toEqual(generateHtmlSite(first));
+    expect(generateMarkdownBook(second)).toEqual(generateMarkdownBook(first));
+  });
+
+  test('entries follow the sorted file order, then source order', async () => {
+    const doc = await scanWorkspace({ workspaceRoot: root });
+
+    // Explicit keys first, then generated keys (see generateCanonicalDoc)
+    expect(Object.keys(doc)).toEqual([
+      'doc.first',
+      'doc.vector',
+      'doc.a_first_length',
+      'doc.math_angle_normalize',
+      'doc.math_vector_vector_length',
+      'doc.z_last_last',
+    ]);
+  });
+
+  test('the order files are given in does not change the output', async () => {
+    const paths = Object.keys(files).map(file => path.join(root, file));
+    const reversed = await scanFilesParallel([...paths].reverse(), root, 1);
+    const sorted = await scanFilesParallel([...paths].sort(compareFilePaths), root, 1);
+
+    expect(serializeCanonicalDoc(reversed)).toBe(serializeCanonicalDoc(sorted));
+  });
+});
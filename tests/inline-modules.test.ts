@@ -0,0 +1,53 @@
+import { describe, expect, test } from 'bun:test';
+import { findEnclosingScopes } from '../src/analyzer/index';
+import { parseSource } from '../src/scanner/index';
+
+const source = [
+  '/// @doc',
+  '/// @description Adds at the crate root',
+  'pub fn add(a: i32, b: i32) -> i32 { a + b }',
+  '',
+  'pub mod arithmetic {',
+  '    /// @doc',
+  '    /// @description Adds integers',
+  '    pub fn add(a: i32, b: i32) -> i32 { a + b }',
+  '}',
+  '',
+  'mod geometry {',
+  '    /// @doc',
+  '    /// @description Adds vectors',
+  '    pub fn add(a: Vec2, b: Vec2) -> Vec2 { a }',
+  '',
+  '    pub mod shapes {',
+  '        /// @doc',
+  '        /// @description Area of a circle',
+  '        pub fn area(radius: f64) -> f64 { radius }',
+  '    }',
+  '}',
+].join('\n');
+
+describe('inline modules', () => {
+  const entries = parseSource(source, 'rust', 'src/lib.rs');
+
+  test('items of sibling inline modules get their own module path and key', () => {
+    expect(entries.map(entry => [entry.id, entry.module])).toEqual([
+      ['add', 'crate'],
+      ['arithmetic_add', 'arithmetic'],
+      ['geometry_add', 'geometry'],
+      ['geometry_shapes_area', 'geometry::shapes'],
+    ]);
+  });
+
+  test('items of a private module are private, whatever their own qualifier', () => {
+    expect(entries.map(entry => entry.private)).toEqual([false, false, true, true]);
+  });
+
+  test('records the visibility of enclosing modules', () => {
+    const lines = source.split('\n');
+
+    expect(findEnclosingScopes(lines, 18, '.rs')).toEqual([
+      { kind: 'module', name: 'geometry', visibility: 'private' },
+      { kind: 'module', name: 'shapes', visibility: 'pub' },
+    ]);
+  });
+});
@@ -0,0 +1,87 @@
+import { describe, expect, test } from 'bun:test';
+import { detectItem, readAttributes } from '../src/analyzer/index';
+import { parseSource } from '../src/scanner/index';
+
+describe('detectItem with attributes', () => {
+  test('skips stacked attributes between the doc block and the item', () => {
+    const lines = [
+      '#[inline]',
+      '#[must_use]',
+      '',
+      '#[allow(clippy::needless_pass_by_value)]',
+      'pub fn add(a: i32, b: i32) -> i32 {',
+      '    a + b',
+      '}',
+    ];
+
+    const item = detectItem(lines, 0, '.rs');
+
+    expect(item?.kind).toBe('function');
+    expect(item?.name).toBe('add');
+    expect(item?.line).toBe(5);
+    expect(item?.signature).toBe('pub fn add(a: i32, b: i32) -> i32');
+    expect(item?.cfg).toBeUndefined();
+  });
+
+  test('reads attributes spanning several lines or sharing a line', () => {
+    const lines = [
+      '#[cfg(all(',
+      '    feature = "serde",',
+      '    not(target_arch = "wasm32"),',
+      '))] #[derive(Debug, Clone)]',
+      '#[doc = "brackets ] in strings"] pub struct Config {',
+      '    name: String,',
+      '}',
+    ];
+
+    const item = detectItem(lines, 0, '.rs');
+
+    expect(item?.kind).toBe('struct');
+    expect(item?.name).toBe('Config');
+    expect(item?.signature).toBe('pub struct Config');
+    expect(item?.cfg).toBe('all( feature = "serde", not(target_arch = "wasm32"), )');
+  });
+
+  test('records the cfg condition, combining several cfg attributes', () => {
+    const lines = [
+      '#[cfg(feature = "json")]',
+      '#[inline]',
+      '#[cfg(unix)]',
+      'pub fn to_json(&self) -> String {',
+      '}',
+    ];
+
+    expect(detectItem(lines, 0, '.rs')?.cfg).toBe('all(feature = "json", unix)');
+  });
+
+  test('returns the position of the code following the attributes', () => {
+    const { attributes, index, column } = readAttributes(['  #[inline] #[cold] fn f() {}'], 0);
+
+    expect(attributes).toEqual(['#[inline]', '#[cold]']);
+    expect(index).toBe(0);
+    expect(column).toBe(19);
+  });
+});
+
+describe('parseSource with attributes', () => {
+  const source = [
+    '/// @doc parse_config parse_config',
+    '/// @description Parses a configuration',
+    '/// @param text Configuration text',
+    '#[cfg(feature = "toml")]',
+    '#[must_use]',
+    '#[inline]',
+    'pub fn parse_config(text: &str) -> Option<Config> {',
+    '}',
+  ].join('\n');
+
+  test('infers types from the signature and exposes the cfg condition', () => {
+    const [entry] = parseSource(source, 'rust');
+
+    expect(entry.kind).toBe('function');
+    expect(entry.params).toEqual([{ name: 'text', type: '&str', description: 'Configuration text' }]);
+    expect(entry.returns?.type).toBe('Option<Config>');
+    expect(entry.cfg).toBe('feature = "toml"');
+    expect(entry.span.itemLine).toBe(7);
+  });
+});
@@ -0,0 +1,78 @@
+import { describe, expect, test } from 'bun:test';
+import { parseSignature } from '../src/analyzer/index';
+import { generateCanonicalDoc, generateMarkdownBook } from '../src/generator/index';
+import { parseSource, scanFile } from '../src/scanner/index';
+
+describe('parseSignature receivers (rust)', () => {
+  test.each([
+    ['fn into_inner(self) -> i32', 'into_inner', 'self'],
+    ['fn into_inner(mut self) -> i32', 'into_inner', 'self'],
+    ['fn boxed(self: Box<Self>)', 'boxed', 'self'],
+    ['fn add(&self, value: i32) -> i32', 'add', '&self'],
+    ["fn get(&'a self) -> &'a i32", 'get', '&self'],
+    ['fn reset(&mut self)', 'reset', '&mut self'],
+    ["fn apply(&'a mut self, value: i32)", 'apply', '&mut self'],
+    ['fn pinned(self: Pin<&mut Self>)', 'pinned', 'self'],
+    ['fn typed(self: &mut Self)', 'typed', '&mut self'],
+    ['pub fn new() -> Self', 'new', 'static'],
+    ['pub fn with_value(value: i32) -> Self', 'with_value', 'static'],
+  ])('%s takes %s', (signature, name, receiver) => {
+    expect(parseSignature(signature, name, '.rs')?.receiver).toBe(receiver);
+  });
+
+  test('other languages have no receiver', () => {
+    expect(parseSignature('add(value: number): number', 'add', '.ts')?.receiver).toBeUndefined();
+  });
+});
+
+describe('method receivers', () => {
+  const source = [
+    '/// @doc',
+    '/// @description A calculator',
+    'pub struct Calculator { value: i32 }',
+    '',
+    'impl Calculator {',
+    '    /// @doc',
+    '    /// @description Creates a calculator',
+    '    pub fn new() -> Self { Calculator { value: 0 } }',
+    '',
+    '    /// @doc',
+    '    /// @description Adds to the current value',
+    '    pub fn add(&self, value: i32) -> i32 { self.value + value }',
+    '',
+    '    /// @doc',
+    '    /// @description Resets the value',
+    '    pub fn reset(&mut self) { self.value = 0; }',
+    '',
+    '    /// @doc',
+    '    /// @description Consumes the calculator',
+    '    pub fn into_inner(self) -> i32 { self.value }',
+    '}',
+    '',
+    '/// @doc',
+    '/// @description A free function',
+    'pub fn helper() {}',
+  ].join('\n');
+  const entries = parseSource(source, 'rust', 'src/calculator.rs');
+
+  test('are recorded on method entries only', () => {
+    expect(entries.map(entry => [entry.name, entry.receiver])).toEqual([
+      ['Calculator', null],
+      ['new', 'static'],
+      ['add', '&self'],
+      ['reset', '&mut self'],
+      ['into_inner', 'self'],
+      ['helper', null],
+    ]);
+  });
+
+  test('are shown next to each method', () => {
+    const book = generateMarkdownBook(generateCanonicalDoc(scanFile('src/calculator.rs', source, '.')));
+    const page = book.find(file => file.content.includes('Creates a calculator'))?.content ?? '';
+
+    expect(page).toContain('*Method* · *static*');
+    expect(page).toContain('*Method* · *(&self)*');
+    expect(page).toContain('*Method* · *(&mut self)*');
+    expect(page).toContain('*Method* · *(self)*');
+  });
+});
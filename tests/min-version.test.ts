@@ -0,0 +1,33 @@
+import { describe, expect, test } from 'bun:test';
+import { captureDiagnostics } from '../src/diagnostics/index';
+import { filterByMinVersion, parseVersion } from '../src/resolver/index';
+import type { CanonicalDoc, DocBlock } from '../src/types/index';
+
+const block = (label: string, line: number, since?: string): DocBlock => ({
+  label,
+  meta: { path: 'src/lib.rs', line, offset: 0, file: 'lib.rs', ext: '.rs', lastEdit: '', key: label },
+  ...(since ? { since: [[since]] } : {}),
+});
+
+describe('filterByMinVersion', () => {
+  const doc = {
+    'doc.old': block('old', 1, '1.4.0'),
+    'doc.new': block('new', 5, 'v2.1'),
+    'doc.undated': block('undated', 9),
+    'doc.unreadable': block('unreadable', 13, 'next'),
+  } as CanonicalDoc;
+
+  test('drops older entries and reports unreadable versions as infos', () => {
+    const { result, diagnostics } = captureDiagnostics(() => filterByMinVersion(doc, parseVersion('2.0.0')!));
+
+    expect(Object.keys(result)).toEqual(['doc.new', 'doc.undated', 'doc.unreadable']);
+    expect(diagnostics).toEqual([
+      {
+        severity: 'info',
+        message: "'unreadable' kept by --min-version: its since version 'next' can't be read",
+        file: 'src/lib.rs',
+        line: 13,
+      },
+    ]);
+  });
+});
@@ -0,0 +1,93 @@
+import { describe, expect, test } from 'bun:test';
+import { parseSignature } from '../src/analyzer/index';
+import { captureDiagnostics } from '../src/diagnostics/index';
+import { parseSource } from '../src/scanner/index';
+
+describe('parseSignature (rust)', () => {
+  test('keeps references, lifetimes and generics in parameter types', () => {
+    const parsed = parseSignature(
+      "pub fn push_all<'a, T: Clone>(target: &'a mut Vec<T>, items: &[T], map: HashMap<String, Vec<T>>) -> usize",
+      'push_all',
+      '.rs'
+    );
+
+    expect(parsed?.params).toEqual([
+      { name: 'target', type: "&'a mut Vec<T>" },
+      { name: 'items', type: '&[T]' },
+      { name: 'map', type: 'HashMap<String, Vec<T>>' },
+    ]);
+    expect(parsed?.returnType).toBe('usize');
+  });
+
+  test('skips receivers and mut bindings', () => {
+    const parsed = parseSignature(
+      "fn apply(&'a mut self, mut f: impl Fn(i32) -> i32, (x, y): (i32, i32))",
+      'apply',
+      '.rs'
+    );
+
+    expect(parsed?.params).toEqual([
+      { name: 'f', type: 'impl Fn(i32) -> i32' },
+      { name: '(x, y)', type: '(i32, i32)' },
+    ]);
+    expect(parsed?.returnType).toBeNull();
+  });
+});
+
+describe('@param type inference', () => {
+  const source = [
+    '/// @doc push_all push_all',
+    '/// @description Pushes clones of items',
+    '/// @param target Vector receiving the items',
+    '/// @param items &[T] Items to push',
+    '/// @param missing Not in the signature',
+    "pub fn push_all<'a, T: Clone>(target: &'a mut Vec<T>, items: &[T]) {",
+    '}',
+  ].join('\n');
+
+  test('fills omitted types from the signature and keeps explicit ones', () => {
+    const [entry] = parseSource(source, 'rust');
+
+    expect(entry.params).toEqual([
+      { name: 'target', type: "&'a mut Vec<T>", description: 'Vector receiving the items' },
+      { name: 'items', type: '&[T]', description: 'Items to push' },
+      { name: 'missing', type: 'unknown', description: 'Not in the signature' },
+    ]);
+  });
+
+  test('warns about a documented type that does not match the signature', () => {
+    const mismatch = [
+      '/// @doc scale scale',
+      '/// @description Scales a value',
+      '/// @param a i64 The value',
+      '/// @param b Vec<u8> The bytes',
+      '/// @param factor The factor',
+      'pub fn scale(a: i32, b: &[u8], factor: f64) -> i32 { a }',
+    ].join('\n');
+    const { result: [entry], diagnostics } = captureDiagnostics(() => parseSource(mismatch, 'rust'));
+
+    expect(entry.params).toEqual([
+      { name: 'a', type: 'i32', description: 'The value' },
+      { name: 'b', type: '&[u8]', description: 'The bytes' },
+      { name: 'factor', type: 'f64', description: 'The factor' },
+    ]);
+    expect(diagnostics.map(diagnostic => diagnostic.message)).toEqual([
+      "documented type 'i64' of @param a does not match signature type 'i32'",
+      "documented type 'Vec<u8>' of @param b does not match signature type '&[u8]'",
+    ]);
+  });
+
+  test('reports inference warnings at the line of their tag', () => {
+    const { diagnostics } = captureDiagnostics(() => parseSource(source, 'rust'));
+
+    expect(diagnostics).toHaveLength(1);
+    expect(diagnostics[0].message).toBe("cannot infer type of @param missing (no parameter 'missing' in signature of push_all)");
+    expect([diagnostics[0].line, diagnostics[0].col]).toEqual([5, 5]);
+  });
+
+  test('falls back to unknown without a signature', () => {
+    const [entry] = parseSource('/// @doc orphan\n/// @param a Description\n', 'rust');
+
+    expect(entry.params).toEqual([{ name: 'a', type: 'unknown', description: 'Description' }]);
+  });
+});
@@ -0,0 +1,93 @@
+import { afterAll, beforeEach, describe, expect, test } from 'bun:test';
+import { existsSync, mkdirSync, mkdtempSync, readFileSync, rmSync, writeFileSync } from 'node:fs';
+import os from 'node:os';
+import path from 'node:path';
+import { captureDiagnostics } from '../src/diagnostics/index';
+import { serializeCanonicalDoc } from '../src/generator/index';
+import { PARSE_CACHE_VERSION, getCacheFile, scanFiles, setParseCacheDir } from '../src/scanner/index';
+
+const root = mkdtempSync(path.join(os.tmpdir(), 'standardoc-cache-'));
+const cacheDir = path.join(root, '.standardoc', 'cache');
+const file = path.join(root, 'src', 'lib.rs');
+const source = [
+  '/// @doc',
+  '/// @description Adds two integers',
+  '/// @paramm a Typo, reported as an unknown tag',
+  'pub fn add(a: i32, b: i32) -> i32 { a + b }',
+].join('\n');
+
+mkdirSync(path.dirname(file), { recursive: true });
+
+const scan = () => {
+  const { result, diagnostics } = captureDiagnostics(() => scanFiles([file], root));
+  return { output: serializeCanonicalDoc(result), warnings: diagnostics.map(diagnostic => diagnostic.message) };
+};
+
+beforeEach(() => {
+  rmSync(cacheDir, { recursive: true, force: true });
+  writeFileSync(file, source, 'utf-8');
+  setParseCacheDir(cacheDir);
+});
+
+afterAll(() => {
+  setParseCacheDir(null);
+  rmSync(root, { recursive: true, force: true });
+});
+
+describe('parse cache', () => {
+  test('stores the blocks of a parsed file and loads them on the next run', () => {
+    const first = scan();
+    expect(existsSync(getCacheFile(cacheDir, file))).toBe(true);
+
+    const entry = JSON.parse(readFileSync(getCacheFile(cacheDir, file), 'utf-8'));
+    expect(entry.version).toBe(PARSE_CACHE_VERSION);
+    expect(entry.filePath).toBe(file);
+    expect(entry.blocks).toHaveLength(1);
+
+    // Diagnostics of the cached parse are reported again
+    const second = scan();
+    expect(second).toEqual(first);
+    expect(second.warnings).toHaveLength(1);
+  });
+
+  test('replays diagnostics at the line and column of their tag', () => {
+    scan();
+    const { diagnostics } = captureDiagnostics(() => scanFiles([file], root));
+
+    expect(diagnostics).toEqual([
+      { severity: 'warning', message: "unknown tag '@paramm'", file: path.join('src', 'lib.rs'), line: 3, col: 5 },
+    ]);
+  });
+
+  test('re-parses a file whose size changed', () => {
+    scan();
+    writeFileSync(file, source.replace('Adds two integers', 'Adds two signed integers'), 'utf-8');
+
+    expect(scan().output).toContain('Adds two signed integers');
+  });
+
+  test('falls back to parsing when a cache file is corrupted', () => {
+    const expected = scan();
+    writeFileSync(getCacheFile(cacheDir, file), '{"version": 1, "blocks": [', 'utf-8');
+
+    expect(scan()).toEqual(expected);
+    // The corrupted file is replaced by a valid entry
+    expect(JSON.parse(readFileSync(getCacheFile(cacheDir, file), 'utf-8')).blocks).toHaveLength(1);
+  });
+
+  test('ignores cache files of another format version', () => {
+    const expected = scan();
+    const cacheFile = getCacheFile(cacheDir, file);
+    const entry = JSON.parse(readFileSync(cacheFile, 'utf-8'));
+    writeFileSync(cacheFile, JSON.stringify({ ...entry, version: PARSE_CACHE_VERSION - 1, blocks: [] }), 'utf-8');
+
+    expect(scan()).toEqual(expected);
+  });
+
+  test('is bypassed when disabled', () => {
+    setParseCacheDir(null);
+    scan();
+
+    expect(existsSync(cacheDir)).toBe(false);
+  });
+});